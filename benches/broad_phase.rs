@@ -0,0 +1,97 @@
+use std::{hint::black_box, time::Instant};
+
+use crimson_recall::{
+    components::{collision::intersects, grid::SpatialGrid},
+    level::Material,
+    sprites::{platform::Platform, player::Player},
+};
+
+const TILE: f64 = 16.0;
+const COLUMNS: usize = 256;
+const ROWS: usize = 36;
+const PROBES: usize = 1000;
+const ROUNDS: usize = 20;
+
+fn tiles() -> Vec<Platform> {
+    (0..COLUMNS)
+        .flat_map(|column| (0..ROWS).map(move |row| (column, row)))
+        .filter(|(column, row)| (column * 7 + row * 13) % 5 == 0)
+        .map(|(column, row)| {
+            Platform::new(
+                column as f64 * TILE,
+                row as f64 * TILE,
+                TILE,
+                TILE,
+                None,
+                "black".to_string(),
+                Material::Sticky,
+            )
+        })
+        .collect()
+}
+
+fn probes() -> Vec<Player> {
+    (0..PROBES)
+        .map(|index| {
+            let x = (index * 97 % (COLUMNS * TILE as usize)) as f64;
+            let y = (index * 31 % (ROWS * TILE as usize)) as f64;
+
+            Player::new(x, y, 50.0, 100.0, None, "blue".to_string())
+        })
+        .collect()
+}
+
+fn bench(name: &str, mut run: impl FnMut() -> usize) -> usize {
+    let start = Instant::now();
+    let mut hits = 0;
+
+    for _ in 0..ROUNDS {
+        hits += black_box(run());
+    }
+
+    let elapsed = start.elapsed() / (ROUNDS * PROBES) as u32;
+
+    println!("{name:<16} {elapsed:>10?} per query ({hits} hits)");
+
+    hits
+}
+
+fn main() {
+    let platforms = tiles();
+    let probes = probes();
+
+    println!("{} platforms, {PROBES} probes", platforms.len());
+
+    let linear = bench("linear scan", || {
+        probes
+            .iter()
+            .map(|probe| {
+                platforms
+                    .iter()
+                    .filter(|platform| intersects(probe, *platform))
+                    .count()
+            })
+            .sum()
+    });
+
+    for cell_size in [64.0, 128.0, 256.0] {
+        let grid = SpatialGrid::new(cell_size, &platforms);
+
+        let hits = bench(&format!("grid ({cell_size})"), || {
+            probes
+                .iter()
+                .map(|probe| {
+                    grid.nearby(&platforms, probe)
+                        .into_iter()
+                        .filter(|platform| intersects(probe, *platform))
+                        .count()
+                })
+                .sum()
+        });
+
+        assert_eq!(
+            hits, linear,
+            "grid ({cell_size}) disagrees with linear scan"
+        );
+    }
+}
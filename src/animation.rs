@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::{
+    components::position::Position,
+    constants::time::TIME,
+    render::{Rect, Renderer},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Clip {
+    Idle,
+    Run,
+    Jump,
+    Fall,
+    Throw,
+    TeleportIn,
+    TeleportOut,
+}
+
+pub struct ClipFrames {
+    row: u32,
+    frames: u32,
+    fps: f64,
+    looping: bool,
+}
+
+impl ClipFrames {
+    pub fn new(row: u32, frames: u32, fps: f64, looping: bool) -> Self {
+        Self {
+            row,
+            frames,
+            fps,
+            looping,
+        }
+    }
+
+    fn duration(&self) -> f64 {
+        self.frames as f64 / self.fps
+    }
+}
+
+pub struct SpriteSheet {
+    image: String,
+    frame_width: f64,
+    frame_height: f64,
+    clips: HashMap<Clip, ClipFrames>,
+}
+
+impl SpriteSheet {
+    pub fn new(
+        image: String,
+        frame_width: f64,
+        frame_height: f64,
+        clips: HashMap<Clip, ClipFrames>,
+    ) -> Self {
+        Self {
+            image,
+            frame_width,
+            frame_height,
+            clips,
+        }
+    }
+
+    pub fn image(&self) -> &str {
+        &self.image
+    }
+
+    fn clip(&self, clip: Clip) -> Option<&ClipFrames> {
+        self.clips
+            .get(&clip)
+            .or_else(|| self.clips.get(&Clip::Idle))
+    }
+
+    fn finished(&self, clip: Clip, time: f64) -> bool {
+        self.clip(clip)
+            .is_none_or(|frames| !frames.looping && time >= frames.duration())
+    }
+
+    fn source(&self, clip: Clip, time: f64) -> Option<Rect> {
+        let frames = self.clip(clip)?;
+        let frame = (time * frames.fps) as u32;
+
+        let frame = if frames.looping {
+            frame % frames.frames.max(1)
+        } else {
+            frame.min(frames.frames.saturating_sub(1))
+        };
+
+        Some(Rect::new(
+            frame as f64 * self.frame_width,
+            frames.row as f64 * self.frame_height,
+            self.frame_width,
+            self.frame_height,
+        ))
+    }
+
+    pub fn draw(
+        &self,
+        renderer: &mut dyn Renderer,
+        animator: &Animator,
+        destination: Rect,
+    ) -> bool {
+        if !renderer.image_ready(&self.image) {
+            return false;
+        }
+
+        let Some(source) = self.source(animator.clip, animator.time) else {
+            return false;
+        };
+
+        if animator.flipped {
+            renderer.push_mirror(destination.x() + destination.width() / 2.0);
+            renderer.draw_image(&self.image, source, destination);
+            renderer.pop_transform();
+        } else {
+            renderer.draw_image(&self.image, source, destination);
+        }
+
+        true
+    }
+}
+
+#[derive(Clone)]
+pub struct Animator {
+    clip: Clip,
+    time: f64,
+    one_shot: bool,
+    flipped: bool,
+    ghost: Option<(Position, f64)>,
+}
+
+impl Animator {
+    pub fn new() -> Self {
+        Self {
+            clip: Clip::Idle,
+            time: 0.0,
+            one_shot: false,
+            flipped: false,
+            ghost: None,
+        }
+    }
+
+    pub fn clip(&self) -> Clip {
+        self.clip
+    }
+
+    pub fn flipped(&self) -> bool {
+        self.flipped
+    }
+
+    pub fn set_flipped(&mut self, flipped: bool) {
+        self.flipped = flipped;
+    }
+
+    pub fn ghost(&self) -> Option<(&Position, Animator)> {
+        self.ghost.as_ref().map(|(position, time)| {
+            (
+                position,
+                Animator {
+                    clip: Clip::TeleportOut,
+                    time: *time,
+                    one_shot: true,
+                    flipped: self.flipped,
+                    ghost: None,
+                },
+            )
+        })
+    }
+
+    pub fn play(&mut self, clip: Clip) {
+        self.clip = clip;
+        self.time = 0.0;
+        self.one_shot = true;
+    }
+
+    pub fn teleport(&mut self, from: Position) {
+        self.ghost = Some((from, 0.0));
+        self.play(Clip::TeleportIn);
+    }
+
+    pub fn update(&mut self, sheet: &SpriteSheet, locomotion: Clip) {
+        self.time += TIME.dt();
+
+        if let Some((_, time)) = &mut self.ghost {
+            *time += TIME.dt();
+
+            if sheet.finished(Clip::TeleportOut, *time) {
+                self.ghost = None;
+            }
+        }
+
+        if self.one_shot && !sheet.finished(self.clip, self.time) {
+            return;
+        }
+
+        self.one_shot = false;
+
+        if self.clip != locomotion {
+            self.clip = locomotion;
+            self.time = 0.0;
+        }
+    }
+}
+
+impl Default for Animator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,163 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    MoveLeft,
+    MoveRight,
+    Jump,
+    Throw,
+    Recall,
+    Restart,
+}
+
+impl Action {
+    pub const ALL: [Action; 6] = [
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Jump,
+        Action::Throw,
+        Action::Recall,
+        Action::Restart,
+    ];
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Binding {
+    Key(String),
+    Mouse(i16),
+    Gamepad(u32),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Bindings {
+    actions: HashMap<Action, Vec<Binding>>,
+}
+
+impl Bindings {
+    pub fn action(&self, binding: &Binding) -> Option<Action> {
+        Action::ALL
+            .into_iter()
+            .find(|action| self.bindings(*action).contains(binding))
+    }
+
+    pub fn bindings(&self, action: Action) -> &[Binding] {
+        self.actions.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn bind(&mut self, action: Action, binding: Binding) {
+        self.unbind(&binding);
+        self.actions.entry(action).or_default().push(binding);
+    }
+
+    pub fn rebind(&mut self, action: Action, binding: Binding) {
+        self.unbind(&binding);
+        self.actions.insert(action, vec![binding]);
+    }
+
+    pub fn hint(&self, action: Action) -> Option<String> {
+        let bindings: Vec<String> = self.bindings(action).iter().map(Binding::label).collect();
+
+        (!bindings.is_empty()).then(|| bindings.join(" or "))
+    }
+
+    pub fn unbind(&mut self, binding: &Binding) {
+        for bindings in self.actions.values_mut() {
+            bindings.retain(|other| other != binding);
+        }
+    }
+}
+
+impl Binding {
+    pub fn label(&self) -> String {
+        match self {
+            Binding::Key(code) => code
+                .strip_prefix("Key")
+                .or_else(|| code.strip_prefix("Digit"))
+                .unwrap_or(code)
+                .to_string(),
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        let key = |code: &str| Binding::Key(code.to_string());
+
+        Self {
+            actions: HashMap::from([
+                (
+                    Action::MoveLeft,
+                    vec![key("KeyA"), key("ArrowLeft"), Binding::Gamepad(14)],
+                ),
+                (
+                    Action::MoveRight,
+                    vec![key("KeyD"), key("ArrowRight"), Binding::Gamepad(15)],
+                ),
+                (
+                    Action::Jump,
+                    vec![
+                        key("KeyW"),
+                        key("Space"),
+                        key("ArrowUp"),
+                        Binding::Gamepad(0),
+                    ],
+                ),
+                (
+                    Action::Throw,
+                    vec![Binding::Mouse(2), Binding::Gamepad(5), Binding::Gamepad(7)],
+                ),
+                (
+                    Action::Recall,
+                    vec![key("KeyE"), Binding::Mouse(0), Binding::Gamepad(2)],
+                ),
+                (Action::Restart, vec![key("KeyR"), Binding::Gamepad(9)]),
+            ]),
+        }
+    }
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(action: &str) -> Result<Self, Self::Err> {
+        Action::ALL
+            .into_iter()
+            .find(|other| format!("{other:?}") == action)
+            .ok_or_else(|| format!("Unknown action « {action} »"))
+    }
+}
+
+impl FromStr for Binding {
+    type Err = String;
+
+    fn from_str(binding: &str) -> Result<Self, Self::Err> {
+        if let Some(button) = binding.strip_prefix("Mouse") {
+            button
+                .parse()
+                .map(Binding::Mouse)
+                .map_err(|_| format!("Unknown mouse button « {button} »"))
+        } else if let Some(button) = binding.strip_prefix("Gamepad") {
+            button
+                .parse()
+                .map(Binding::Gamepad)
+                .map_err(|_| format!("Unknown gamepad button « {button} »"))
+        } else if binding.is_empty() {
+            Err("Empty binding".to_string())
+        } else {
+            Ok(Binding::Key(binding.to_string()))
+        }
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Binding::Key(code) => write!(f, "{code}"),
+            Binding::Mouse(button) => write!(f, "Mouse{button}"),
+            Binding::Gamepad(button) => write!(f, "Gamepad{button}"),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    components::position::Position,
+    constants::{camera::CAMERA, time::TIME},
+    level::Area,
+    sprites::Sprite,
+    world::World,
+};
+
+pub struct Camera {
+    position: Position,
+    previous_position: Position,
+    width: f64,
+    height: f64,
+}
+
+impl Camera {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self {
+            position: Position::new(0.0, 0.0),
+            previous_position: Position::new(0.0, 0.0),
+            width,
+            height,
+        }
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn interpolated_position(&self, alpha: f64) -> Position {
+        self.previous_position.lerp(&self.position, alpha)
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    pub fn to_world(&self, screen: &Position) -> Position {
+        Position::new(
+            screen.x() + self.position.x(),
+            screen.y() + self.position.y(),
+        )
+    }
+
+    pub fn to_screen(&self, world: &Position) -> Position {
+        Position::new(world.x() - self.position.x(), world.y() - self.position.y())
+    }
+
+    pub fn snap(&mut self, world: &World) {
+        let player = world.player();
+
+        self.position = self.clamp(
+            player.position().x() + player.width() / 2.0 - self.width / 2.0,
+            player.position().y() + player.height() / 2.0 - self.height / 2.0,
+            world.bounds(),
+        );
+        self.previous_position = self.position;
+    }
+
+    pub fn follow(&mut self, world: &World, aim: &Position) {
+        if world.stats().ticks() <= 1 {
+            self.snap(world);
+            return;
+        }
+
+        self.previous_position = self.position;
+
+        let player = world.player();
+        let center_x = player.position().x() + player.width() / 2.0;
+        let center_y = player.position().y() + player.height() / 2.0;
+
+        let dx = aim.x() - center_x;
+        let dy = aim.y() - center_y;
+        let distance = dx.hypot(dy);
+
+        let look_ahead = if distance > 0.0 {
+            CAMERA.look_ahead() * (distance / CAMERA.aim_range()).min(1.0) / distance
+        } else {
+            0.0
+        };
+
+        let target_x = center_x + dx * look_ahead;
+        let target_y = center_y + dy * look_ahead;
+
+        let desired = self.clamp(
+            deadzone(
+                self.position.x(),
+                self.width,
+                CAMERA.deadzone_width(),
+                target_x,
+            ),
+            deadzone(
+                self.position.y(),
+                self.height,
+                CAMERA.deadzone_height(),
+                target_y,
+            ),
+            world.bounds(),
+        );
+
+        let blend = 1.0 - (-CAMERA.smoothing() * TIME.dt()).exp();
+
+        self.position = self.position.lerp(&desired, blend);
+    }
+
+    fn clamp(&self, x: f64, y: f64, bounds: &Area) -> Position {
+        Position::new(
+            clamp_axis(x, self.width, bounds.x, bounds.width),
+            clamp_axis(y, self.height, bounds.y, bounds.height),
+        )
+    }
+}
+
+fn deadzone(position: f64, size: f64, deadzone: f64, target: f64) -> f64 {
+    let center = position + size / 2.0;
+    let half = deadzone / 2.0;
+
+    if target < center - half {
+        target + half - size / 2.0
+    } else if target > center + half {
+        target - half - size / 2.0
+    } else {
+        position
+    }
+}
+
+fn clamp_axis(position: f64, size: f64, start: f64, length: f64) -> f64 {
+    if length <= size {
+        start + (length - size) / 2.0
+    } else {
+        position.clamp(start, start + length - size)
+    }
+}
@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bindings::Action,
+    constants::{controls::ThrowMode, time::TIME},
+    input::InputFrame,
+    level::Level,
+    replay::{Playback, Replay, ReplayError},
+    world::World,
+};
+
+const SUMMARY_DURATION: f64 = 3.0;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Manifest {
+    pub levels: Vec<String>,
+}
+
+impl Manifest {
+    pub fn from_ron(source: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(source)
+    }
+}
+
+pub struct LevelSummary {
+    name: String,
+    time: f64,
+    throws: u32,
+    teleports: u32,
+    last: bool,
+}
+
+impl LevelSummary {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn throws(&self) -> u32 {
+        self.throws
+    }
+
+    pub fn teleports(&self) -> u32 {
+        self.teleports
+    }
+
+    pub fn last(&self) -> bool {
+        self.last
+    }
+}
+
+pub struct Campaign {
+    levels: Vec<Level>,
+    current: usize,
+    seed: u64,
+    throw_mode: ThrowMode,
+    world: World,
+    summary: Option<LevelSummary>,
+    summary_ticks: u32,
+    recording: Replay,
+    finished_recording: Option<Replay>,
+    playback: Option<Playback>,
+}
+
+impl Campaign {
+    pub fn new(levels: Vec<Level>, seed: u64) -> Option<Self> {
+        let level = levels.first()?;
+        let world = World::from_level_seeded(level, seed);
+        let recording = Replay::new(level.name.clone(), seed, ThrowMode::default());
+
+        Some(Self {
+            levels,
+            current: 0,
+            seed,
+            throw_mode: ThrowMode::default(),
+            world,
+            summary: None,
+            summary_ticks: 0,
+            recording,
+            finished_recording: None,
+            playback: None,
+        })
+    }
+
+    pub fn step(&mut self, input: &InputFrame) {
+        if input.just_pressed(Action::Restart) {
+            self.restart();
+            return;
+        }
+
+        if let Some(playback) = &mut self.playback {
+            match playback.next_frame() {
+                Some(frame) => self.world.step(&frame),
+                None => self.restart(),
+            }
+            return;
+        }
+
+        if self.summary.is_some() {
+            self.step_summary();
+            return;
+        }
+
+        self.recording.record(input);
+        self.world.step(input);
+
+        if self.world.completed() {
+            let recording = std::mem::replace(
+                &mut self.recording,
+                Replay::new(self.world.name().to_string(), self.seed, self.throw_mode),
+            );
+            self.finished_recording = Some(recording);
+
+            let stats = self.world.stats();
+
+            self.summary = Some(LevelSummary {
+                name: self.world.name().to_string(),
+                time: stats.time(),
+                throws: stats.throws(),
+                teleports: stats.teleports(),
+                last: self.current + 1 == self.levels.len(),
+            });
+            self.summary_ticks = (SUMMARY_DURATION * TIME.tick_rate()) as u32;
+        }
+    }
+
+    fn step_summary(&mut self) {
+        if self.summary.as_ref().is_some_and(LevelSummary::last) {
+            return;
+        }
+
+        self.summary_ticks = self.summary_ticks.saturating_sub(1);
+
+        if self.summary_ticks == 0 {
+            self.load(self.current + 1);
+        }
+    }
+
+    pub fn restart(&mut self) {
+        self.load(self.current);
+    }
+
+    pub fn load(&mut self, index: usize) {
+        self.load_seeded(index, self.seed);
+    }
+
+    fn load_seeded(&mut self, index: usize, seed: u64) {
+        let Some(level) = self.levels.get(index) else {
+            return;
+        };
+
+        let recording = std::mem::replace(
+            &mut self.recording,
+            Replay::new(level.name.clone(), seed, self.throw_mode),
+        );
+
+        if self.playback.is_none() && recording.ticks() > 0 {
+            self.finished_recording = Some(recording);
+        }
+
+        self.current = index;
+        self.world = World::from_level_seeded(level, seed);
+        self.world.set_throw_mode(self.throw_mode);
+        self.summary = None;
+        self.summary_ticks = 0;
+        self.playback = None;
+    }
+
+    pub fn play(&mut self, replay: Replay) -> Result<(), ReplayError> {
+        let Some(index) = self
+            .levels
+            .iter()
+            .position(|level| level.name == replay.level())
+        else {
+            return Err(ReplayError::UnknownLevel(replay.level().to_string()));
+        };
+
+        self.load_seeded(index, replay.seed());
+        self.world.set_throw_mode(replay.throw_mode());
+        self.playback = Some(Playback::new(replay));
+
+        Ok(())
+    }
+
+    pub fn throw_mode(&self) -> ThrowMode {
+        self.throw_mode
+    }
+
+    pub fn set_throw_mode(&mut self, throw_mode: ThrowMode) {
+        if self.throw_mode == throw_mode {
+            return;
+        }
+
+        self.throw_mode = throw_mode;
+
+        if !self.playing() {
+            self.restart();
+        }
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playback.is_some()
+    }
+
+    pub fn recording(&self) -> &Replay {
+        &self.recording
+    }
+
+    pub fn take_finished_recording(&mut self) -> Option<Replay> {
+        self.finished_recording.take()
+    }
+
+    pub fn world(&self) -> &World {
+        &self.world
+    }
+
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    pub fn levels(&self) -> &[Level] {
+        &self.levels
+    }
+
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn summary(&self) -> Option<&LevelSummary> {
+        self.summary.as_ref()
+    }
+}
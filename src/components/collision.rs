@@ -1,5 +1,8 @@
 use crate::sprites::Sprite;
 
+use super::position::Position;
+
+#[derive(Clone, Copy)]
 pub enum Collision {
     Left,
     Right,
@@ -7,28 +10,437 @@ pub enum Collision {
     Bottom,
 }
 
+pub struct Contact {
+    normal_x: f64,
+    normal_y: f64,
+    depth: f64,
+}
+
+impl Contact {
+    pub fn normal_x(&self) -> f64 {
+        self.normal_x
+    }
+
+    pub fn normal_y(&self) -> f64 {
+        self.normal_y
+    }
+
+    pub fn depth(&self) -> f64 {
+        self.depth
+    }
+
+    pub fn side(&self) -> Collision {
+        if self.normal_x.abs() > self.normal_y.abs() {
+            if self.normal_x < 0.0 {
+                Collision::Left
+            } else {
+                Collision::Right
+            }
+        } else if self.normal_y < 0.0 {
+            Collision::Top
+        } else {
+            Collision::Bottom
+        }
+    }
+}
+
+pub struct Hit {
+    time: f64,
+    side: Collision,
+}
+
+impl Hit {
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn side(&self) -> &Collision {
+        &self.side
+    }
+}
+
 pub fn intersects(sprite: &impl Sprite, other: &impl Sprite) -> bool {
-    !(sprite.position().x() + sprite.collision_box().width() <= other.position().x()
-        || sprite.position().x() >= other.position().x() + other.collision_box().width()
+    if sprite.angle() != 0.0 || other.angle() != 0.0 {
+        return contact(sprite, other).is_some();
+    }
+
+    let sprite_x = box_x(sprite, sprite.position());
+    let other_x = box_x(other, other.position());
+
+    !(sprite_x + sprite.collision_box().width() <= other_x
+        || sprite_x >= other_x + other.collision_box().width()
         || sprite.position().y() + sprite.collision_box().height() <= other.position().y()
         || sprite.position().y() >= other.position().y() + other.collision_box().height())
 }
 
 pub fn collision_side(sprite: &impl Sprite, other: &impl Sprite) -> Option<Collision> {
-    if sprite.previous_position().x() + sprite.collision_box().width() <= other.position().x() {
-        return Some(Collision::Left);
-    } else if sprite.previous_position().x() >= other.position().x() + other.collision_box().width()
-    {
-        return Some(Collision::Right);
+    let previous_x = box_x(sprite, sprite.previous_position());
+    let other_x = box_x(other, other.position());
+
+    if previous_x + sprite.collision_box().width() <= other_x {
+        Some(Collision::Left)
+    } else if previous_x >= other_x + other.collision_box().width() {
+        Some(Collision::Right)
     } else if sprite.previous_position().y() + sprite.collision_box().height()
         <= other.position().y()
     {
-        return Some(Collision::Top);
+        Some(Collision::Top)
     } else if sprite.previous_position().y()
         >= other.position().y() + other.collision_box().height()
     {
-        return Some(Collision::Bottom);
+        Some(Collision::Bottom)
     } else {
+        None
+    }
+}
+
+pub fn half_extents(sprite: &impl Sprite) -> (f64, f64) {
+    let (sin, cos) = sprite.angle().sin_cos();
+    let half_width = sprite.collision_box().width() / 2.0;
+    let half_height = sprite.collision_box().height() / 2.0;
+
+    (
+        half_width * cos.abs() + half_height * sin.abs(),
+        half_width * sin.abs() + half_height * cos.abs(),
+    )
+}
+
+pub fn box_x(sprite: &impl Sprite, position: &Position) -> f64 {
+    position.x() + sprite.collision_box().offset_x()
+}
+
+fn center(sprite: &impl Sprite) -> Position {
+    Position::new(
+        box_x(sprite, sprite.position()) + sprite.collision_box().width() / 2.0,
+        sprite.position().y() + sprite.collision_box().height() / 2.0,
+    )
+}
+
+fn axes(sprite: &impl Sprite) -> [(f64, f64); 2] {
+    let (sin, cos) = sprite.angle().sin_cos();
+
+    [(cos, sin), (-sin, cos)]
+}
+
+pub fn corners(sprite: &impl Sprite) -> [Position; 4] {
+    let center = center(sprite);
+    let [(ux, uy), (vx, vy)] = axes(sprite);
+    let half_width = sprite.collision_box().width() / 2.0;
+    let half_height = sprite.collision_box().height() / 2.0;
+
+    [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)].map(|(u, v)| {
+        Position::new(
+            center.x() + ux * half_width * u + vx * half_height * v,
+            center.y() + uy * half_width * u + vy * half_height * v,
+        )
+    })
+}
+
+fn project(corners: &[Position; 4], (axis_x, axis_y): (f64, f64)) -> (f64, f64) {
+    corners
+        .iter()
+        .map(|corner| corner.x() * axis_x + corner.y() * axis_y)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+pub fn contact(sprite: &impl Sprite, other: &impl Sprite) -> Option<Contact> {
+    let sprite_corners = corners(sprite);
+    let other_corners = corners(other);
+
+    let mut best: Option<Contact> = None;
+
+    for (axis_x, axis_y) in axes(sprite).into_iter().chain(axes(other)) {
+        let (sprite_min, sprite_max) = project(&sprite_corners, (axis_x, axis_y));
+        let (other_min, other_max) = project(&other_corners, (axis_x, axis_y));
+
+        let depth = sprite_max.min(other_max) - sprite_min.max(other_min);
+
+        if depth <= 0.0 {
+            return None;
+        }
+
+        if best.as_ref().is_some_and(|best| best.depth <= depth) {
+            continue;
+        }
+
+        let direction = if sprite_min + sprite_max < other_min + other_max {
+            -1.0
+        } else {
+            1.0
+        };
+
+        best = Some(Contact {
+            normal_x: axis_x * direction,
+            normal_y: axis_y * direction,
+            depth,
+        });
+    }
+
+    best
+}
+
+fn sweep_axis(start: f64, delta: f64, size: f64, other_start: f64, other_size: f64) -> (f64, f64) {
+    if delta > 0.0 {
+        (
+            (other_start - (start + size)) / delta,
+            (other_start + other_size - start) / delta,
+        )
+    } else if delta < 0.0 {
+        (
+            (other_start + other_size - start) / delta,
+            (other_start - (start + size)) / delta,
+        )
+    } else if start + size <= other_start || start >= other_start + other_size {
+        (f64::INFINITY, f64::NEG_INFINITY)
+    } else {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+pub fn sweep_box(
+    start: &Position,
+    end: &Position,
+    width: f64,
+    height: f64,
+    other: &impl Sprite,
+) -> Option<Hit> {
+    let dx = end.x() - start.x();
+    let dy = end.y() - start.y();
+
+    let (x_entry, x_exit) = sweep_axis(
+        start.x(),
+        dx,
+        width,
+        box_x(other, other.position()),
+        other.collision_box().width(),
+    );
+    let (y_entry, y_exit) = sweep_axis(
+        start.y(),
+        dy,
+        height,
+        other.position().y(),
+        other.collision_box().height(),
+    );
+
+    let entry = x_entry.max(y_entry);
+    let exit = x_exit.min(y_exit);
+
+    if entry >= exit || !(0.0..=1.0).contains(&entry) {
         return None;
     }
+
+    let side = if x_entry > y_entry {
+        if dx > 0.0 {
+            Collision::Left
+        } else {
+            Collision::Right
+        }
+    } else if dy > 0.0 {
+        Collision::Top
+    } else {
+        Collision::Bottom
+    };
+
+    Some(Hit { time: entry, side })
+}
+
+pub fn sweep(sprite: &impl Sprite, other: &impl Sprite) -> Option<Hit> {
+    let start = sprite.previous_position();
+    let end = sprite.position();
+
+    sweep_box(
+        &Position::new(box_x(sprite, start), start.y()),
+        &Position::new(box_x(sprite, end), end.y()),
+        sprite.collision_box().width(),
+        sprite.collision_box().height(),
+        other,
+    )
+}
+
+pub fn earliest_hit<'a, S: Sprite + 'a>(
+    others: impl IntoIterator<Item = &'a S>,
+    mut sweep: impl FnMut(&S) -> Option<Hit>,
+) -> Option<(&'a S, Hit)> {
+    others
+        .into_iter()
+        .filter_map(|other| sweep(other).map(|hit| (other, hit)))
+        .min_by(|(_, first), (_, second)| first.time.total_cmp(&second.time))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_4;
+
+    use super::*;
+    use crate::{
+        components::{grid::SpatialGrid, hit_box::HitBox},
+        level::{Area, Material},
+        sprites::platform::Platform,
+    };
+
+    struct Body {
+        position: Position,
+        previous_position: Position,
+        collision_box: HitBox,
+        angle: f64,
+    }
+
+    impl Body {
+        fn new(x: f64, y: f64, width: f64, height: f64, angle: f64) -> Self {
+            Self {
+                position: Position::new(x, y),
+                previous_position: Position::new(x, y),
+                collision_box: HitBox::new(width, height),
+                angle,
+            }
+        }
+
+        fn moving(from: (f64, f64), to: (f64, f64), size: f64) -> Self {
+            Self {
+                previous_position: Position::new(from.0, from.1),
+                ..Self::new(to.0, to.1, size, size, 0.0)
+            }
+        }
+    }
+
+    impl Sprite for Body {
+        fn position(&self) -> &Position {
+            &self.position
+        }
+
+        fn previous_position(&self) -> &Position {
+            &self.previous_position
+        }
+
+        fn width(&self) -> f64 {
+            self.collision_box.width()
+        }
+
+        fn height(&self) -> f64 {
+            self.collision_box.height()
+        }
+
+        fn collision_box(&self) -> &HitBox {
+            &self.collision_box
+        }
+
+        fn color(&self) -> &str {
+            "black"
+        }
+
+        fn angle(&self) -> f64 {
+            self.angle
+        }
+
+        fn apply_physics(&mut self, _: f64, _: &Area) {}
+
+        fn resolve_collisions(&mut self, _: &[Platform], _: &SpatialGrid) {}
+    }
+
+    fn platform(x: f64, y: f64, width: f64, height: f64) -> Platform {
+        Platform::new(
+            x,
+            y,
+            width,
+            height,
+            None,
+            "gray".to_string(),
+            Material::Sticky,
+        )
+    }
+
+    #[test]
+    fn fast_box_hits_thin_platform() {
+        let wall = platform(100.0, 0.0, 2.0, 100.0);
+        let floor = platform(0.0, 100.0, 200.0, 2.0);
+
+        let across = Body::moving((0.0, 40.0), (200.0, 40.0), 10.0);
+        let hit = sweep(&across, &wall).unwrap();
+
+        assert!(!intersects(&across, &wall));
+        assert_eq!(hit.time(), 0.45);
+        assert!(matches!(hit.side(), Collision::Left));
+
+        let back = Body::moving((200.0, 40.0), (0.0, 40.0), 10.0);
+        assert!(matches!(
+            sweep(&back, &wall).unwrap().side(),
+            Collision::Right
+        ));
+
+        let down = Body::moving((50.0, 0.0), (50.0, 300.0), 10.0);
+        let hit = sweep(&down, &floor).unwrap();
+
+        assert_eq!(hit.time(), 0.3);
+        assert!(matches!(hit.side(), Collision::Top));
+    }
+
+    #[test]
+    fn box_starting_inside_is_not_swept() {
+        let wall = platform(100.0, 0.0, 2.0, 100.0);
+        let body = Body::moving((95.0, 40.0), (200.0, 40.0), 10.0);
+
+        assert!(sweep(&body, &wall).is_none());
+    }
+
+    #[test]
+    fn earliest_hit_wins() {
+        let platforms = [
+            platform(150.0, 0.0, 2.0, 100.0),
+            platform(100.0, 0.0, 2.0, 100.0),
+            platform(300.0, 0.0, 2.0, 100.0),
+        ];
+        let body = Body::moving((0.0, 40.0), (200.0, 40.0), 10.0);
+
+        let (platform, hit) = earliest_hit(&platforms, |platform| sweep(&body, platform)).unwrap();
+
+        assert_eq!(platform.position().x(), 100.0);
+        assert_eq!(hit.time(), 0.45);
+    }
+
+    #[test]
+    fn rotated_boxes_push_apart_along_shallowest_axis() {
+        let first = Body::new(0.0, 0.0, 20.0, 20.0, FRAC_PI_4);
+        let second = Body::new(20.0, 5.0, 20.0, 20.0, FRAC_PI_4);
+
+        let contact = contact(&first, &second).unwrap();
+        let depth = 20.0 - 25.0 * FRAC_PI_4.cos();
+
+        assert!((contact.normal_x() + FRAC_PI_4.cos()).abs() < 1e-9);
+        assert!((contact.normal_y() + FRAC_PI_4.sin()).abs() < 1e-9);
+        assert!((contact.depth() - depth).abs() < 1e-9);
+        assert!(intersects(&first, &second));
+    }
+
+    #[test]
+    fn separation_on_second_shapes_axis() {
+        let square = Body::new(0.0, 0.0, 20.0, 20.0, 0.0);
+        let diamond = Body::new(23.0, 23.0, 20.0, 20.0, FRAC_PI_4);
+
+        let (half_width, half_height) = half_extents(&diamond);
+        assert!(33.0 - half_width < 20.0 && 33.0 - half_height < 20.0);
+
+        assert!(contact(&square, &diamond).is_none());
+        assert!(!intersects(&square, &diamond));
+    }
+
+    #[test]
+    fn contact_side_follows_dominant_normal() {
+        let side = |normal_x, normal_y| {
+            Contact {
+                normal_x,
+                normal_y,
+                depth: 1.0,
+            }
+            .side()
+        };
+
+        assert!(matches!(side(-1.0, 0.0), Collision::Left));
+        assert!(matches!(side(1.0, 0.0), Collision::Right));
+        assert!(matches!(side(0.0, -1.0), Collision::Top));
+        assert!(matches!(side(0.0, 1.0), Collision::Bottom));
+        assert!(matches!(side(0.6, -0.8), Collision::Top));
+        assert!(matches!(side(-0.8, 0.6), Collision::Left));
+    }
 }
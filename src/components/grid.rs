@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use crate::sprites::Sprite;
+
+use super::collision::{box_x, half_extents};
+
+pub struct SpatialGrid {
+    cell_size: f64,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl SpatialGrid {
+    pub fn new<S: Sprite>(cell_size: f64, sprites: &[S]) -> Self {
+        let mut grid = Self {
+            cell_size,
+            cells: HashMap::new(),
+        };
+
+        for (index, sprite) in sprites.iter().enumerate() {
+            let (min_x, min_y, max_x, max_y) = bounds(sprite);
+
+            for cell in grid.cells_in(min_x, min_y, max_x, max_y) {
+                grid.cells.entry(cell).or_default().push(index);
+            }
+        }
+
+        grid
+    }
+
+    pub fn cell_size(&self) -> f64 {
+        self.cell_size
+    }
+
+    fn cell(&self, value: f64) -> i64 {
+        (value / self.cell_size).floor() as i64
+    }
+
+    fn cells_in(
+        &self,
+        min_x: f64,
+        min_y: f64,
+        max_x: f64,
+        max_y: f64,
+    ) -> impl Iterator<Item = (i64, i64)> + use<> {
+        let (first_x, last_x) = (self.cell(min_x), self.cell(max_x));
+        let (first_y, last_y) = (self.cell(min_y), self.cell(max_y));
+
+        (first_x..=last_x).flat_map(move |x| (first_y..=last_y).map(move |y| (x, y)))
+    }
+
+    pub fn query(&self, min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<usize> {
+        let mut indices: Vec<usize> = self
+            .cells_in(min_x, min_y, max_x, max_y)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .collect();
+
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+
+    pub fn query_swept(&self, sprite: &impl Sprite) -> Vec<usize> {
+        let (min_x, min_y, max_x, max_y) = bounds(sprite);
+
+        let dx = sprite.previous_position().x() - sprite.position().x();
+        let dy = sprite.previous_position().y() - sprite.position().y();
+
+        self.query(
+            min_x + dx.min(0.0),
+            min_y + dy.min(0.0),
+            max_x + dx.max(0.0),
+            max_y + dy.max(0.0),
+        )
+    }
+
+    pub fn nearby<'a, S: Sprite>(&self, sprites: &'a [S], sprite: &impl Sprite) -> Vec<&'a S> {
+        self.query_swept(sprite)
+            .into_iter()
+            .filter_map(|index| sprites.get(index))
+            .collect()
+    }
+}
+
+fn bounds(sprite: &impl Sprite) -> (f64, f64, f64, f64) {
+    let (half_width, half_height) = half_extents(sprite);
+    let center_x = box_x(sprite, sprite.position()) + sprite.collision_box().width() / 2.0;
+    let center_y = sprite.position().y() + sprite.collision_box().height() / 2.0;
+
+    (
+        center_x - half_width,
+        center_y - half_height,
+        center_x + half_width,
+        center_y + half_height,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{components::collision::intersects, level::Material, sprites::platform::Platform};
+
+    fn platform(x: f64, y: f64, width: f64, height: f64) -> Platform {
+        Platform::new(
+            x,
+            y,
+            width,
+            height,
+            None,
+            "gray".to_string(),
+            Material::Sticky,
+        )
+    }
+
+    #[test]
+    fn nearby_matches_linear_scan() {
+        let platforms: Vec<Platform> = (-6..6)
+            .flat_map(|column| (-4..4).map(move |row| (column, row)))
+            .map(|(column, row)| {
+                platform(
+                    column as f64 * 45.0 - 7.0,
+                    row as f64 * 37.0 + 3.0,
+                    30.0 + (column + 6) as f64 * 4.0,
+                    12.0 + (row + 4) as f64 * 5.0,
+                )
+            })
+            .collect();
+        let grid = SpatialGrid::new(64.0, &platforms);
+        let mut hits = 0;
+
+        for probe in (-10..10)
+            .flat_map(|x| (-8..8).map(move |y| (x, y)))
+            .map(|(x, y)| platform(x as f64 * 29.0 + 0.5, y as f64 * 23.0 - 0.5, 40.0, 70.0))
+        {
+            let linear: Vec<usize> = (0..platforms.len())
+                .filter(|&index| intersects(&probe, &platforms[index]))
+                .collect();
+            let candidates = grid.query_swept(&probe);
+
+            assert!(linear.iter().all(|index| candidates.contains(index)));
+            assert_eq!(
+                grid.nearby(&platforms, &probe)
+                    .into_iter()
+                    .filter(|platform| intersects(&probe, *platform))
+                    .count(),
+                linear.len()
+            );
+
+            hits += linear.len();
+        }
+
+        assert!(hits > 0);
+    }
+
+    #[test]
+    fn sprites_on_cell_borders_fill_every_cell() {
+        let platforms = [platform(-10.0, -10.0, 20.0, 20.0)];
+        let grid = SpatialGrid::new(64.0, &platforms);
+
+        for (x, y) in [(-5.0, -5.0), (5.0, -5.0), (-5.0, 5.0), (5.0, 5.0)] {
+            assert_eq!(grid.query(x, y, x, y), [0]);
+        }
+
+        assert!(grid.query(70.0, 70.0, 80.0, 80.0).is_empty());
+    }
+}
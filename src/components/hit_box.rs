@@ -1,11 +1,20 @@
 pub struct HitBox {
+    offset_x: f64,
     width: f64,
     height: f64,
 }
 
 impl HitBox {
     pub fn new(width: f64, height: f64) -> Self {
-        Self { width, height }
+        Self {
+            offset_x: 0.0,
+            width,
+            height,
+        }
+    }
+
+    pub fn offset_x(&self) -> f64 {
+        self.offset_x
     }
 
     pub fn width(&self) -> f64 {
@@ -16,6 +25,10 @@ impl HitBox {
         self.height
     }
 
+    pub fn set_offset_x(&mut self, offset_x: f64) {
+        self.offset_x = offset_x;
+    }
+
     pub fn set_width(&mut self, width: f64) {
         self.width = width;
     }
@@ -1,4 +1,6 @@
 pub mod collision;
+pub mod grid;
 pub mod hit_box;
 pub mod position;
+pub mod rng;
 pub mod velocity;
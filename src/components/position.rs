@@ -1,3 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Position {
     x: f64,
     y: f64,
@@ -31,4 +34,11 @@ impl Position {
     pub fn mutate_y(&mut self, y: f64) {
         self.y += y;
     }
+
+    pub fn lerp(&self, other: &Position, time: f64) -> Position {
+        Position::new(
+            self.x + (other.x - self.x) * time,
+            self.y + (other.y - self.y) * time,
+        )
+    }
 }
@@ -1,3 +1,4 @@
+#[derive(Clone, Copy)]
 pub struct Velocity {
     x: f64,
     y: f64,
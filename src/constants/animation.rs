@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+
+use crate::animation::{Clip, ClipFrames, SpriteSheet};
+
+pub static PLAYER_SHEET: Lazy<SpriteSheet> = Lazy::new(|| {
+    SpriteSheet::new(
+        "assets/player.png".to_string(),
+        50.0,
+        100.0,
+        HashMap::from([
+            (Clip::Idle, ClipFrames::new(0, 2, 2.0, true)),
+            (Clip::Run, ClipFrames::new(1, 4, 10.0, true)),
+            (Clip::Jump, ClipFrames::new(2, 2, 8.0, false)),
+            (Clip::Fall, ClipFrames::new(3, 2, 8.0, true)),
+            (Clip::Throw, ClipFrames::new(4, 3, 12.0, false)),
+            (Clip::TeleportIn, ClipFrames::new(5, 4, 16.0, false)),
+            (Clip::TeleportOut, ClipFrames::new(6, 4, 16.0, false)),
+        ]),
+    )
+});
+
+pub static WEAPON_SHEET: Lazy<SpriteSheet> = Lazy::new(|| {
+    SpriteSheet::new(
+        "assets/weapon.png".to_string(),
+        15.0,
+        50.0,
+        HashMap::from([
+            (Clip::Idle, ClipFrames::new(0, 1, 1.0, true)),
+            (Clip::Throw, ClipFrames::new(1, 4, 16.0, true)),
+        ]),
+    )
+});
@@ -0,0 +1,37 @@
+pub struct CameraFollow {
+    deadzone_width: f64,
+    deadzone_height: f64,
+    look_ahead: f64,
+    aim_range: f64,
+    smoothing: f64,
+}
+
+impl CameraFollow {
+    pub fn deadzone_width(&self) -> f64 {
+        self.deadzone_width
+    }
+
+    pub fn deadzone_height(&self) -> f64 {
+        self.deadzone_height
+    }
+
+    pub fn look_ahead(&self) -> f64 {
+        self.look_ahead
+    }
+
+    pub fn aim_range(&self) -> f64 {
+        self.aim_range
+    }
+
+    pub fn smoothing(&self) -> f64 {
+        self.smoothing
+    }
+}
+
+pub static CAMERA: CameraFollow = CameraFollow {
+    deadzone_width: 160.0,
+    deadzone_height: 120.0,
+    look_ahead: 120.0,
+    aim_range: 400.0,
+    smoothing: 6.0,
+};
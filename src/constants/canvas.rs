@@ -21,5 +21,5 @@ impl Canvas {
 pub static CANVAS: Canvas = Canvas {
     width: 1024.0,
     height: 576.0,
-    gravity: 0.5,
+    gravity: 1800.0,
 };
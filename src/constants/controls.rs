@@ -1,46 +1,47 @@
 use once_cell::sync::Lazy;
-use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+use std::{str::FromStr, sync::Mutex};
 
-pub struct Keys {
-    pub d: KeyState,
-    pub a: KeyState,
-    pub w: KeyState,
+use crate::bindings::{Action, Bindings};
+
+pub static BINDINGS: Lazy<Mutex<Bindings>> = Lazy::new(|| Mutex::new(Bindings::default()));
+
+pub static CAPTURE: Lazy<Mutex<Option<Action>>> = Lazy::new(|| Mutex::new(None));
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct Deadzones {
+    pub movement: f64,
+    pub aim: f64,
 }
 
-pub struct KeyState {
-    pub pressed: bool,
+impl Default for Deadzones {
+    fn default() -> Self {
+        Self {
+            movement: 0.2,
+            aim: 0.25,
+        }
+    }
 }
 
-pub static KEYS: Lazy<Mutex<Keys>> = Lazy::new(|| {
-    Mutex::new(Keys {
-        d: KeyState { pressed: false },
-        a: KeyState { pressed: false },
-        w: KeyState { pressed: false },
-    })
-});
-
-pub struct Mouse {
-    pub left: MouseState,
-    pub right: MouseState,
+pub static DEADZONES: Lazy<Mutex<Deadzones>> = Lazy::new(|| Mutex::new(Deadzones::default()));
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThrowMode {
+    #[default]
+    Charge,
+    Distance,
 }
 
-pub struct MouseState {
-    pub pressed: bool,
-    pub x: f64,
-    pub y: f64,
+impl FromStr for ThrowMode {
+    type Err = String;
+
+    fn from_str(mode: &str) -> Result<Self, Self::Err> {
+        match mode {
+            "Charge" => Ok(ThrowMode::Charge),
+            "Distance" => Ok(ThrowMode::Distance),
+            _ => Err(format!("Unknown throw mode « {mode} »")),
+        }
+    }
 }
 
-pub static MOUSE: Lazy<Mutex<Mouse>> = Lazy::new(|| {
-    Mutex::new(Mouse {
-        left: MouseState {
-            pressed: false,
-            x: 0.0,
-            y: 0.0,
-        },
-        right: MouseState {
-            pressed: false,
-            x: 0.0,
-            y: 0.0,
-        },
-    })
-});
+pub static THROW_MODE: Lazy<Mutex<ThrowMode>> = Lazy::new(|| Mutex::new(ThrowMode::default()));
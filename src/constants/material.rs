@@ -0,0 +1,31 @@
+pub struct MaterialResponse {
+    restitution: f64,
+    friction: f64,
+    rest_speed: f64,
+    glass_damping: f64,
+}
+
+impl MaterialResponse {
+    pub fn restitution(&self) -> f64 {
+        self.restitution
+    }
+
+    pub fn friction(&self) -> f64 {
+        self.friction
+    }
+
+    pub fn rest_speed(&self) -> f64 {
+        self.rest_speed
+    }
+
+    pub fn glass_damping(&self) -> f64 {
+        self.glass_damping
+    }
+}
+
+pub static MATERIAL: MaterialResponse = MaterialResponse {
+    restitution: 0.7,
+    friction: 4.0,
+    rest_speed: 60.0,
+    glass_damping: 0.6,
+};
@@ -1,4 +1,10 @@
+pub mod animation;
+pub mod camera;
 pub mod canvas;
 pub mod controls;
+pub mod material;
 pub mod player;
+pub mod teleport;
+pub mod time;
+pub mod touch;
 pub mod weapon;
@@ -19,8 +19,9 @@ pub static PLAYER: Player = Player {
         height: 200.0,
     },
     velocity: PlayerVelocity {
-        x: 5.0,
-        jump: -10.0,
+        x: 300.0,
+        jump: -600.0,
+        air_drag: 1.5,
     },
 };
 
@@ -42,6 +43,7 @@ impl PlayerSprite {
 pub struct PlayerVelocity {
     x: f64,
     jump: f64,
+    air_drag: f64,
 }
 
 impl PlayerVelocity {
@@ -52,4 +54,8 @@ impl PlayerVelocity {
     pub fn jump(&self) -> f64 {
         self.jump
     }
+
+    pub fn air_drag(&self) -> f64 {
+        self.air_drag
+    }
 }
@@ -0,0 +1,25 @@
+pub struct TeleportSearch {
+    step: f64,
+    radius: f64,
+    feedback_time: f64,
+}
+
+impl TeleportSearch {
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    pub fn feedback_time(&self) -> f64 {
+        self.feedback_time
+    }
+}
+
+pub static TELEPORT: TeleportSearch = TeleportSearch {
+    step: 8.0,
+    radius: 128.0,
+    feedback_time: 0.4,
+};
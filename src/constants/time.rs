@@ -0,0 +1,23 @@
+pub struct Time {
+    tick_rate: f64,
+    max_frame_time: f64,
+}
+
+impl Time {
+    pub fn tick_rate(&self) -> f64 {
+        self.tick_rate
+    }
+
+    pub fn dt(&self) -> f64 {
+        1.0 / self.tick_rate
+    }
+
+    pub fn max_frame_time(&self) -> f64 {
+        self.max_frame_time
+    }
+}
+
+pub static TIME: Time = Time {
+    tick_rate: 120.0,
+    max_frame_time: 0.25,
+};
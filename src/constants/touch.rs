@@ -0,0 +1,56 @@
+pub struct TouchLayout {
+    joystick_zone_width: f64,
+    joystick_radius: f64,
+    jump_x: f64,
+    jump_y: f64,
+    jump_size: f64,
+    tap_distance: f64,
+    tap_margin: f64,
+}
+
+impl TouchLayout {
+    pub fn joystick_zone_width(&self) -> f64 {
+        self.joystick_zone_width
+    }
+
+    pub fn joystick_radius(&self) -> f64 {
+        self.joystick_radius
+    }
+
+    pub fn jump_x(&self) -> f64 {
+        self.jump_x
+    }
+
+    pub fn jump_y(&self) -> f64 {
+        self.jump_y
+    }
+
+    pub fn jump_size(&self) -> f64 {
+        self.jump_size
+    }
+
+    pub fn tap_distance(&self) -> f64 {
+        self.tap_distance
+    }
+
+    pub fn tap_margin(&self) -> f64 {
+        self.tap_margin
+    }
+
+    pub fn in_jump_button(&self, x: f64, y: f64) -> bool {
+        x >= self.jump_x
+            && x <= self.jump_x + self.jump_size
+            && y >= self.jump_y
+            && y <= self.jump_y + self.jump_size
+    }
+}
+
+pub static TOUCH_LAYOUT: TouchLayout = TouchLayout {
+    joystick_zone_width: 400.0,
+    joystick_radius: 60.0,
+    jump_x: 884.0,
+    jump_y: 436.0,
+    jump_size: 100.0,
+    tap_distance: 12.0,
+    tap_margin: 20.0,
+};
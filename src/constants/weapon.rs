@@ -4,12 +4,22 @@ use super::player::PLAYER;
 
 pub struct Weapon {
     sprite: WeaponSprite,
+    throw: WeaponThrow,
+    recall: WeaponRecall,
 }
 
 impl Weapon {
     pub fn sprite(&self) -> &WeaponSprite {
         &self.sprite
     }
+
+    pub fn throw(&self) -> &WeaponThrow {
+        &self.throw
+    }
+
+    pub fn recall(&self) -> &WeaponRecall {
+        &self.recall
+    }
 }
 
 const WEAPON_Y_RATIO: f64 = 1.0 / 5.0;
@@ -21,6 +31,20 @@ pub static WEAPON: Lazy<Weapon> = Lazy::new(|| Weapon {
         x_offset: PLAYER.sprite().width(),
         y_offset: PLAYER.sprite().height() * WEAPON_Y_RATIO,
     },
+    throw: WeaponThrow {
+        power_ratio: 6.0,
+        min_power: 300.0,
+        max_power: 1200.0,
+        charge_time: 1.0,
+        spin_ratio: 0.02,
+        spin_threshold: 400.0,
+    },
+    recall: WeaponRecall {
+        speed: 900.0,
+        steering: 6.0,
+        catch_distance: 40.0,
+        collides: false,
+    },
 });
 
 pub struct WeaponSprite {
@@ -47,3 +71,67 @@ impl WeaponSprite {
         self.y_offset
     }
 }
+
+pub struct WeaponThrow {
+    power_ratio: f64,
+    min_power: f64,
+    max_power: f64,
+    charge_time: f64,
+    spin_ratio: f64,
+    spin_threshold: f64,
+}
+
+impl WeaponThrow {
+    pub fn power_ratio(&self) -> f64 {
+        self.power_ratio
+    }
+
+    pub fn min_power(&self) -> f64 {
+        self.min_power
+    }
+
+    pub fn max_power(&self) -> f64 {
+        self.max_power
+    }
+
+    pub fn charge_time(&self) -> f64 {
+        self.charge_time
+    }
+
+    pub fn spin_ratio(&self) -> f64 {
+        self.spin_ratio
+    }
+
+    pub fn spin_threshold(&self) -> f64 {
+        self.spin_threshold
+    }
+
+    pub fn charged_power(&self, charge: f64) -> f64 {
+        self.min_power + (self.max_power - self.min_power) * charge.clamp(0.0, 1.0)
+    }
+}
+
+pub struct WeaponRecall {
+    speed: f64,
+    steering: f64,
+    catch_distance: f64,
+    collides: bool,
+}
+
+impl WeaponRecall {
+    pub fn speed(&self) -> f64 {
+        self.speed
+    }
+
+    pub fn steering(&self) -> f64 {
+        self.steering
+    }
+
+    pub fn catch_distance(&self) -> f64 {
+        self.catch_distance
+    }
+
+    pub fn collides(&self) -> bool {
+        self.collides
+    }
+}
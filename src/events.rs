@@ -1,58 +1,121 @@
+use std::cell::RefCell;
+
 use wasm_bindgen::JsCast;
-use web_sys::{Event, KeyboardEvent, MouseEvent};
+use web_sys::{Event, HtmlCanvasElement, KeyboardEvent, MouseEvent};
+
+use crate::{
+    bindings::Binding,
+    components::position::Position,
+    constants::{
+        canvas::CANVAS,
+        controls::{BINDINGS, CAPTURE},
+    },
+    input::{InputEvent, InputQueue},
+    settings::save_bindings,
+};
+
+pub fn canvas_position(canvas: &HtmlCanvasElement, client_x: f64, client_y: f64) -> Position {
+    let rect = canvas.get_bounding_client_rect();
+
+    if rect.width() == 0.0 || rect.height() == 0.0 {
+        return Position::new(client_x, client_y);
+    }
 
-use crate::constants::controls::{KEYS, MOUSE};
+    Position::new(
+        (client_x - rect.left()) * CANVAS.width() / rect.width(),
+        (client_y - rect.top()) * CANVAS.height() / rect.height(),
+    )
+}
 
-pub fn key_down(event: &Event) {
-    let event = event.dyn_ref::<KeyboardEvent>().unwrap();
+fn capture(binding: &Binding) -> bool {
+    let Ok(mut capture) = CAPTURE.lock() else {
+        return false;
+    };
+
+    let Some(action) = capture.take() else {
+        return false;
+    };
+
+    let Ok(mut bindings) = BINDINGS.lock() else {
+        return false;
+    };
 
-    let Ok(mut keys) = KEYS.lock() else { return };
+    bindings.rebind(action, binding.clone());
+    save_bindings(&bindings);
 
-    match event.key().to_lowercase().as_str() {
-        "a" => keys.a.pressed = true,
-        "d" => keys.d.pressed = true,
-        "w" | " " => keys.w.pressed = true,
-        _ => {}
+    true
+}
+
+fn push_action(queue: &RefCell<InputQueue>, binding: &Binding, pressed: bool) -> bool {
+    let Some(action) = BINDINGS
+        .lock()
+        .ok()
+        .and_then(|bindings| bindings.action(binding))
+    else {
+        return false;
+    };
+
+    queue.borrow_mut().push(if pressed {
+        InputEvent::Pressed(action)
+    } else {
+        InputEvent::Released(action)
+    });
+
+    true
+}
+
+pub fn press(queue: &RefCell<InputQueue>, binding: &Binding) -> bool {
+    capture(binding) || push_action(queue, binding, true)
+}
+
+pub fn release(queue: &RefCell<InputQueue>, binding: &Binding) -> bool {
+    push_action(queue, binding, false)
+}
+
+pub fn key_down(queue: &RefCell<InputQueue>, event: &Event) {
+    let event = event.dyn_ref::<KeyboardEvent>().unwrap();
+
+    if press(queue, &Binding::Key(event.code())) {
+        event.prevent_default();
     }
 }
 
-pub fn key_up(event: &Event) {
+pub fn key_up(queue: &RefCell<InputQueue>, event: &Event) {
     let event = event.dyn_ref::<KeyboardEvent>().unwrap();
 
-    let Ok(mut keys) = KEYS.lock() else { return };
+    release(queue, &Binding::Key(event.code()));
+}
 
-    match event.key().to_lowercase().as_str() {
-        "a" => keys.a.pressed = false,
-        "d" => keys.d.pressed = false,
-        "w" | " " => keys.w.pressed = false,
-        _ => {}
-    }
+fn push_cursor(queue: &RefCell<InputQueue>, canvas: &HtmlCanvasElement, event: &MouseEvent) {
+    queue.borrow_mut().push(InputEvent::Cursor(canvas_position(
+        canvas,
+        event.client_x() as f64,
+        event.client_y() as f64,
+    )));
 }
 
-pub fn left_click(event: &Event) {
+pub fn mouse_move(queue: &RefCell<InputQueue>, canvas: &HtmlCanvasElement, event: &Event) {
     let event = event.dyn_ref::<MouseEvent>().unwrap();
 
-    if event.button() != 0 {
-        return;
-    };
+    push_cursor(queue, canvas, event);
+}
+
+pub fn mouse_down(queue: &RefCell<InputQueue>, canvas: &HtmlCanvasElement, event: &Event) {
+    let event = event.dyn_ref::<MouseEvent>().unwrap();
 
-    let Ok(mut mouse) = MOUSE.lock() else { return };
+    push_cursor(queue, canvas, event);
 
-    mouse.left.pressed = true;
+    if press(queue, &Binding::Mouse(event.button())) {
+        event.prevent_default();
+    }
 }
 
-pub fn right_click(event: &Event) {
+pub fn mouse_up(queue: &RefCell<InputQueue>, event: &Event) {
     let event = event.dyn_ref::<MouseEvent>().unwrap();
 
-    if event.button() != 2 {
-        return;
-    };
+    release(queue, &Binding::Mouse(event.button()));
+}
 
+pub fn context_menu(event: &Event) {
     event.prevent_default();
-
-    let Ok(mut mouse) = MOUSE.lock() else { return };
-
-    mouse.right.pressed = true;
-    mouse.right.x = event.client_x() as f64;
-    mouse.right.y = event.client_y() as f64;
 }
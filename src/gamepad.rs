@@ -0,0 +1,103 @@
+use std::cell::RefCell;
+
+use wasm_bindgen::JsCast;
+use web_sys::GamepadButton;
+
+use crate::{
+    bindings::Binding,
+    components::position::Position,
+    constants::controls::DEADZONES,
+    events::{press, release},
+    get_window,
+    input::{InputQueue, apply_deadzone},
+    sprites::{Sprite, player::Player},
+};
+
+const AIM_RADIUS: f64 = 200.0;
+
+#[derive(Default)]
+pub struct GamepadState {
+    buttons: Vec<bool>,
+    move_x: f64,
+    aim: Option<(f64, f64)>,
+}
+
+impl GamepadState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn poll(&mut self, queue: &RefCell<InputQueue>) {
+        let Ok(gamepads) = get_window().navigator().get_gamepads() else {
+            return;
+        };
+
+        let Some(gamepad) = gamepads
+            .iter()
+            .find_map(|gamepad| gamepad.dyn_into::<web_sys::Gamepad>().ok())
+        else {
+            for (index, pressed) in self.buttons.iter().enumerate() {
+                if *pressed {
+                    release(queue, &Binding::Gamepad(index as u32));
+                }
+            }
+
+            self.buttons.clear();
+            self.move_x = 0.0;
+            self.aim = None;
+            return;
+        };
+
+        let buttons: Vec<bool> = gamepad
+            .buttons()
+            .iter()
+            .filter_map(|button| button.dyn_into::<GamepadButton>().ok())
+            .map(|button| button.pressed())
+            .collect();
+
+        for (index, pressed) in buttons.iter().enumerate() {
+            let previous = self.buttons.get(index).copied().unwrap_or(false);
+            let binding = Binding::Gamepad(index as u32);
+
+            if *pressed && !previous {
+                press(queue, &binding);
+            } else if !*pressed && previous {
+                release(queue, &binding);
+            }
+        }
+
+        self.buttons = buttons;
+
+        let axes: Vec<f64> = gamepad
+            .axes()
+            .iter()
+            .map(|axis| axis.as_f64().unwrap_or(0.0))
+            .collect();
+        let axis = |index: usize| axes.get(index).copied().unwrap_or(0.0);
+
+        let deadzones = DEADZONES
+            .lock()
+            .map(|deadzones| *deadzones)
+            .unwrap_or_default();
+
+        let (move_x, _) = apply_deadzone(axis(0), axis(1), deadzones.movement);
+        self.move_x = move_x;
+
+        let (aim_x, aim_y) = apply_deadzone(axis(2), axis(3), deadzones.aim);
+        self.aim = (aim_x != 0.0 || aim_y != 0.0).then_some((aim_x, aim_y));
+    }
+
+    pub fn move_x(&self) -> f64 {
+        self.move_x
+    }
+
+    pub fn reticle(&self, player: &Player) -> Option<Position> {
+        let (x, y) = self.aim?;
+        let position = player.position();
+
+        Some(Position::new(
+            position.x() + player.width() / 2.0 + x * AIM_RADIUS,
+            position.y() + player.height() / 2.0 + y * AIM_RADIUS,
+        ))
+    }
+}
@@ -0,0 +1,224 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{bindings::Action, components::position::Position};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActionSet(u8);
+
+impl ActionSet {
+    pub fn contains(&self, action: Action) -> bool {
+        self.0 & (1 << action as u8) != 0
+    }
+
+    pub fn insert(&mut self, action: Action) {
+        self.0 |= 1 << action as u8;
+    }
+
+    pub fn remove(&mut self, action: Action) {
+        self.0 &= !(1 << action as u8);
+    }
+}
+
+pub enum InputEvent {
+    Pressed(Action),
+    Released(Action),
+    Cursor(Position),
+    Move(f64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputFrame {
+    held: ActionSet,
+    just_pressed: ActionSet,
+    just_released: ActionSet,
+    cursor: Position,
+    move_x: f64,
+}
+
+impl InputFrame {
+    pub fn new(
+        held: ActionSet,
+        just_pressed: ActionSet,
+        just_released: ActionSet,
+        cursor: Position,
+        move_x: f64,
+    ) -> Self {
+        Self {
+            held,
+            just_pressed,
+            just_released,
+            cursor,
+            move_x,
+        }
+    }
+
+    pub fn with_cursor(mut self, cursor: Position) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    pub fn held(&self, action: Action) -> bool {
+        self.held.contains(action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(action)
+    }
+
+    pub fn just_released(&self, action: Action) -> bool {
+        self.just_released.contains(action)
+    }
+
+    pub fn cursor(&self) -> &Position {
+        &self.cursor
+    }
+
+    pub fn move_x(&self) -> f64 {
+        self.move_x
+    }
+}
+
+impl Default for InputFrame {
+    fn default() -> Self {
+        Self::new(
+            ActionSet::default(),
+            ActionSet::default(),
+            ActionSet::default(),
+            Position::new(0.0, 0.0),
+            0.0,
+        )
+    }
+}
+
+pub struct InputQueue {
+    events: Vec<InputEvent>,
+    held: ActionSet,
+    cursor: Position,
+    move_x: f64,
+}
+
+impl InputQueue {
+    pub fn new() -> Self {
+        Self {
+            events: Vec::new(),
+            held: ActionSet::default(),
+            cursor: Position::new(0.0, 0.0),
+            move_x: 0.0,
+        }
+    }
+
+    pub fn push(&mut self, event: InputEvent) {
+        self.events.push(event);
+    }
+
+    pub fn drain(&mut self) -> InputFrame {
+        let mut just_pressed = ActionSet::default();
+        let mut just_released = ActionSet::default();
+
+        for event in self.events.drain(..) {
+            match event {
+                InputEvent::Pressed(action) => {
+                    if !self.held.contains(action) {
+                        just_pressed.insert(action);
+                    }
+                    self.held.insert(action);
+                }
+                InputEvent::Released(action) => {
+                    if self.held.contains(action) {
+                        just_released.insert(action);
+                    }
+                    self.held.remove(action);
+                }
+                InputEvent::Cursor(cursor) => self.cursor = cursor,
+                InputEvent::Move(move_x) => self.move_x = move_x,
+            }
+        }
+
+        InputFrame::new(
+            self.held,
+            just_pressed,
+            just_released,
+            self.cursor,
+            self.move_x,
+        )
+    }
+}
+
+impl Default for InputQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn apply_deadzone(x: f64, y: f64, deadzone: f64) -> (f64, f64) {
+    let magnitude = (x.powi(2) + y.powi(2)).sqrt();
+
+    if magnitude <= deadzone || deadzone >= 1.0 {
+        return (0.0, 0.0);
+    }
+
+    let scale = ((magnitude - deadzone) / (1.0 - deadzone)).min(1.0) / magnitude;
+
+    (x * scale, y * scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn press_and_release_in_one_frame() {
+        let mut queue = InputQueue::new();
+
+        queue.push(InputEvent::Pressed(Action::Jump));
+        queue.push(InputEvent::Released(Action::Jump));
+        let frame = queue.drain();
+
+        assert!(frame.just_pressed(Action::Jump));
+        assert!(frame.just_released(Action::Jump));
+        assert!(!frame.held(Action::Jump));
+
+        let frame = queue.drain();
+
+        assert!(!frame.just_pressed(Action::Jump));
+        assert!(!frame.just_released(Action::Jump));
+    }
+
+    #[test]
+    fn key_repeat_is_not_a_new_press() {
+        let mut queue = InputQueue::new();
+
+        queue.push(InputEvent::Pressed(Action::Throw));
+        queue.push(InputEvent::Pressed(Action::Throw));
+        assert!(queue.drain().just_pressed(Action::Throw));
+
+        queue.push(InputEvent::Pressed(Action::Throw));
+        let frame = queue.drain();
+
+        assert!(!frame.just_pressed(Action::Throw));
+        assert!(frame.held(Action::Throw));
+    }
+
+    #[test]
+    fn held_carries_over_between_drains() {
+        let mut queue = InputQueue::new();
+
+        queue.push(InputEvent::Pressed(Action::MoveRight));
+        queue.push(InputEvent::Cursor(Position::new(10.0, 20.0)));
+        queue.drain();
+
+        for _ in 0..3 {
+            let frame = queue.drain();
+
+            assert!(frame.held(Action::MoveRight));
+            assert!(!frame.just_pressed(Action::MoveRight));
+            assert_eq!(frame.cursor(), &Position::new(10.0, 20.0));
+        }
+
+        queue.push(InputEvent::Released(Action::MoveRight));
+        let frame = queue.drain();
+
+        assert!(!frame.held(Action::MoveRight));
+        assert!(frame.just_released(Action::MoveRight));
+    }
+}
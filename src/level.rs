@@ -0,0 +1,511 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    constants::{canvas::CANVAS, player::PLAYER, weapon::WEAPON},
+    tilemap::Tilemap,
+};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Level {
+    pub name: String,
+    #[serde(default = "default_background")]
+    pub background: String,
+    #[serde(default)]
+    pub gravity: Option<f64>,
+    #[serde(default)]
+    pub width: Option<f64>,
+    #[serde(default)]
+    pub height: Option<f64>,
+    pub player_spawn: Spawn,
+    #[serde(default)]
+    pub weapon_spawn: Option<Spawn>,
+    #[serde(default)]
+    pub goal: Option<Area>,
+    #[serde(default)]
+    pub platforms: Vec<PlatformDescription>,
+    #[serde(default)]
+    pub tilemap: Option<TilemapDescription>,
+    #[serde(default)]
+    pub air_teleport: Option<Momentum>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+pub enum Momentum {
+    Keep,
+    Inherit,
+    Zero,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct Spawn {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct Area {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PlatformDescription {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub color: String,
+    #[serde(default)]
+    pub material: Material,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Material {
+    #[default]
+    Sticky,
+    Bouncy,
+    Metal,
+    Glass,
+    NoTeleport,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TilemapDescription {
+    pub tileset: String,
+    pub tile_size: f64,
+    #[serde(default)]
+    pub x: f64,
+    #[serde(default)]
+    pub y: f64,
+    #[serde(default = "default_tile_color")]
+    pub color: String,
+    pub rows: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum LevelError {
+    Parse(String),
+    Invalid(Vec<ValidationError>),
+}
+
+#[derive(Debug)]
+pub enum ValidationError {
+    InvalidGravity,
+    InvalidBounds,
+    EmptyPlatform { platform: usize },
+    EmptyGoal,
+    PlatformOutOfBounds { platform: usize },
+    PlayerSpawnOutOfBounds,
+    WeaponSpawnOutOfBounds,
+    GoalOutOfBounds,
+    PlayerSpawnOverlaps { platform: usize },
+    WeaponSpawnOverlaps { platform: usize },
+    PlayerSpawnInGoal,
+    InvalidTileSize,
+    PlayerSpawnInTilemap,
+    WeaponSpawnInTilemap,
+    TilemapOutOfBounds,
+}
+
+fn default_background() -> String {
+    "lightgrey".to_string()
+}
+
+fn default_tile_color() -> String {
+    "dimgray".to_string()
+}
+
+impl Area {
+    pub fn overlaps(&self, other: &Area) -> bool {
+        !(self.x + self.width <= other.x
+            || self.x >= other.x + other.width
+            || self.y + self.height <= other.y
+            || self.y >= other.y + other.height)
+    }
+
+    pub fn inside(&self, bounds: &Area) -> bool {
+        self.x >= bounds.x
+            && self.y >= bounds.y
+            && self.x + self.width <= bounds.x + bounds.width
+            && self.y + self.height <= bounds.y + bounds.height
+    }
+}
+
+impl PlatformDescription {
+    pub fn area(&self) -> Area {
+        Area {
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Level {
+    pub fn from_ron(source: &str) -> Result<Self, LevelError> {
+        let level: Level =
+            ron::from_str(source).map_err(|error| LevelError::Parse(error.to_string()))?;
+        level.validate()?;
+        Ok(level)
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, LevelError> {
+        let level: Level =
+            serde_json::from_str(source).map_err(|error| LevelError::Parse(error.to_string()))?;
+        level.validate()?;
+        Ok(level)
+    }
+
+    pub fn bounds(&self) -> Area {
+        Area {
+            x: 0.0,
+            y: 0.0,
+            width: self.width.unwrap_or(CANVAS.width()),
+            height: self.height.unwrap_or(CANVAS.height()),
+        }
+    }
+
+    pub fn player_area(&self) -> Area {
+        let width = match self.weapon_spawn {
+            Some(_) => PLAYER.sprite().width(),
+            None => PLAYER.sprite().width() + WEAPON.sprite().width(),
+        };
+
+        Area {
+            x: self.player_spawn.x,
+            y: self.player_spawn.y,
+            width,
+            height: PLAYER.sprite().height(),
+        }
+    }
+
+    pub fn weapon_area(&self) -> Option<Area> {
+        self.weapon_spawn.map(|spawn| Area {
+            x: spawn.x,
+            y: spawn.y,
+            width: WEAPON.sprite().width(),
+            height: WEAPON.sprite().height(),
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), LevelError> {
+        let mut errors = Vec::new();
+
+        if self.gravity.is_some_and(|gravity| !gravity.is_finite()) {
+            errors.push(ValidationError::InvalidGravity);
+        }
+
+        let bounds = self.bounds();
+
+        let positive = |value: f64| value.is_finite() && value > 0.0;
+
+        if !positive(bounds.width) || !positive(bounds.height) {
+            errors.push(ValidationError::InvalidBounds);
+        }
+
+        let player = self.player_area();
+        let weapon = self.weapon_area();
+
+        if !player.inside(&bounds) {
+            errors.push(ValidationError::PlayerSpawnOutOfBounds);
+        }
+
+        if weapon.is_some_and(|weapon| !weapon.inside(&bounds)) {
+            errors.push(ValidationError::WeaponSpawnOutOfBounds);
+        }
+
+        if let Some(goal) = &self.goal {
+            if goal.width <= 0.0 || goal.height <= 0.0 {
+                errors.push(ValidationError::EmptyGoal);
+            } else if !goal.inside(&bounds) {
+                errors.push(ValidationError::GoalOutOfBounds);
+            } else if goal.overlaps(&player) {
+                errors.push(ValidationError::PlayerSpawnInGoal);
+            }
+        }
+
+        for (index, platform) in self.platforms.iter().enumerate() {
+            let area = platform.area();
+
+            if area.width <= 0.0 || area.height <= 0.0 {
+                errors.push(ValidationError::EmptyPlatform { platform: index });
+                continue;
+            }
+
+            if !area.overlaps(&bounds) {
+                errors.push(ValidationError::PlatformOutOfBounds { platform: index });
+            }
+
+            if area.overlaps(&player) {
+                errors.push(ValidationError::PlayerSpawnOverlaps { platform: index });
+            }
+
+            if weapon.is_some_and(|weapon| area.overlaps(&weapon)) {
+                errors.push(ValidationError::WeaponSpawnOverlaps { platform: index });
+            }
+        }
+
+        if let Some(tilemap) = &self.tilemap {
+            if !tilemap.tile_size.is_finite() || tilemap.tile_size <= 0.0 {
+                errors.push(ValidationError::InvalidTileSize);
+            } else {
+                let areas = Tilemap::new(tilemap).collision_areas();
+
+                if areas.iter().any(|area| !area.overlaps(&bounds)) {
+                    errors.push(ValidationError::TilemapOutOfBounds);
+                }
+
+                if areas.iter().any(|area| area.overlaps(&player)) {
+                    errors.push(ValidationError::PlayerSpawnInTilemap);
+                }
+
+                if weapon.is_some_and(|weapon| areas.iter().any(|area| area.overlaps(&weapon))) {
+                    errors.push(ValidationError::WeaponSpawnInTilemap);
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(LevelError::Invalid(errors))
+        }
+    }
+}
+
+impl fmt::Display for LevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LevelError::Parse(error) => write!(f, "Could not parse level: {error}"),
+            LevelError::Invalid(errors) => {
+                write!(f, "Invalid level:")?;
+                for error in errors {
+                    write!(f, "\n  - {error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::InvalidGravity => write!(f, "gravity must be a finite number"),
+            ValidationError::InvalidBounds => write!(f, "level width and height must be positive"),
+            ValidationError::EmptyPlatform { platform } => {
+                write!(f, "platform #{platform} has no area")
+            }
+            ValidationError::EmptyGoal => write!(f, "goal has no area"),
+            ValidationError::PlatformOutOfBounds { platform } => {
+                write!(f, "platform #{platform} lies outside of the level")
+            }
+            ValidationError::PlayerSpawnOutOfBounds => {
+                write!(f, "player spawn is not inside the level")
+            }
+            ValidationError::WeaponSpawnOutOfBounds => {
+                write!(f, "weapon spawn is not inside the level")
+            }
+            ValidationError::GoalOutOfBounds => write!(f, "goal is not inside the level"),
+            ValidationError::PlayerSpawnOverlaps { platform } => {
+                write!(f, "player spawn overlaps platform #{platform}")
+            }
+            ValidationError::WeaponSpawnOverlaps { platform } => {
+                write!(f, "weapon spawn overlaps platform #{platform}")
+            }
+            ValidationError::PlayerSpawnInGoal => write!(f, "player spawn overlaps the goal"),
+            ValidationError::InvalidTileSize => write!(f, "tilemap tile size must be positive"),
+            ValidationError::PlayerSpawnInTilemap => {
+                write!(f, "player spawn overlaps a tilemap tile")
+            }
+            ValidationError::WeaponSpawnInTilemap => {
+                write!(f, "weapon spawn overlaps a tilemap tile")
+            }
+            ValidationError::TilemapOutOfBounds => {
+                write!(f, "tilemap has tiles outside of the level")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LevelError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(fields: &str) -> Vec<ValidationError> {
+        match Level::from_ron(&format!("(name: \"Test\", {fields})")) {
+            Ok(_) => Vec::new(),
+            Err(LevelError::Invalid(errors)) => errors,
+            Err(error) => panic!("{error}"),
+        }
+    }
+
+    #[test]
+    fn valid_level_passes() {
+        assert!(errors("player_spawn: (x: 40.0, y: 376.0)").is_empty());
+    }
+
+    #[test]
+    fn gravity_must_be_finite() {
+        let errors = errors("gravity: Some(inf), player_spawn: (x: 40.0, y: 376.0)");
+
+        assert!(matches!(errors[..], [ValidationError::InvalidGravity]));
+    }
+
+    #[test]
+    fn bounds_must_be_positive() {
+        let errors = errors("height: Some(-1.0), player_spawn: (x: 40.0, y: 376.0)");
+
+        assert!(matches!(errors[0], ValidationError::InvalidBounds));
+    }
+
+    #[test]
+    fn platform_needs_an_area() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             platforms: [(x: 500.0, y: 100.0, width: 0.0, height: 10.0, color: \"gray\")]",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::EmptyPlatform { platform: 0 }]
+        ));
+    }
+
+    #[test]
+    fn goal_needs_an_area() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             goal: Some((x: 500.0, y: 100.0, width: 80.0, height: 0.0))",
+        );
+
+        assert!(matches!(errors[..], [ValidationError::EmptyGoal]));
+    }
+
+    #[test]
+    fn platform_must_touch_the_level() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             platforms: [(x: 2000.0, y: 100.0, width: 40.0, height: 10.0, color: \"gray\")]",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::PlatformOutOfBounds { platform: 0 }]
+        ));
+    }
+
+    #[test]
+    fn player_spawn_must_be_inside() {
+        let errors = errors("player_spawn: (x: -10.0, y: 376.0)");
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::PlayerSpawnOutOfBounds]
+        ));
+    }
+
+    #[test]
+    fn weapon_spawn_must_be_inside() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             weapon_spawn: Some((x: 1000.0, y: 100.0))",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::WeaponSpawnOutOfBounds]
+        ));
+    }
+
+    #[test]
+    fn goal_must_be_inside() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             goal: Some((x: 1000.0, y: 456.0, width: 80.0, height: 120.0))",
+        );
+
+        assert!(matches!(errors[..], [ValidationError::GoalOutOfBounds]));
+    }
+
+    #[test]
+    fn player_spawn_must_be_clear_of_platforms() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             platforms: [(x: 100.0, y: 400.0, width: 50.0, height: 20.0, color: \"gray\")]",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::PlayerSpawnOverlaps { platform: 0 }]
+        ));
+    }
+
+    #[test]
+    fn weapon_spawn_must_be_clear_of_platforms() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             weapon_spawn: Some((x: 600.0, y: 100.0)), \
+             platforms: [(x: 610.0, y: 150.0, width: 50.0, height: 20.0, color: \"gray\")]",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::WeaponSpawnOverlaps { platform: 0 }]
+        ));
+    }
+
+    #[test]
+    fn player_spawn_must_be_outside_the_goal() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             goal: Some((x: 100.0, y: 400.0, width: 80.0, height: 80.0))",
+        );
+
+        assert!(matches!(errors[..], [ValidationError::PlayerSpawnInGoal]));
+    }
+
+    #[test]
+    fn tile_size_must_be_positive() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             tilemap: Some((tileset: \"tiles.png\", tile_size: 0.0, rows: [\"1\"]))",
+        );
+
+        assert!(matches!(errors[..], [ValidationError::InvalidTileSize]));
+    }
+
+    #[test]
+    fn player_spawn_must_be_clear_of_tiles() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             tilemap: Some((tileset: \"tiles.png\", tile_size: 32.0, y: 544.0, rows: [\"1111\"]))",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::PlayerSpawnInTilemap]
+        ));
+    }
+
+    #[test]
+    fn weapon_spawn_must_be_clear_of_tiles() {
+        let errors = errors(
+            "player_spawn: (x: 40.0, y: 376.0), \
+             weapon_spawn: Some((x: 600.0, y: 100.0)), \
+             tilemap: Some((tileset: \"tiles.png\", tile_size: 32.0, x: 600.0, y: 150.0, rows: [\"1\"]))",
+        );
+
+        assert!(matches!(
+            errors[..],
+            [ValidationError::WeaponSpawnInTilemap]
+        ));
+    }
+}
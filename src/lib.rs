@@ -1,22 +1,57 @@
-use components::hit_box::HitBox;
-use constants::{canvas::CANVAS, player::PLAYER, weapon::WEAPON};
-use gloo::events::{EventListener, EventListenerOptions};
-use sprites::{
-    Sprite,
-    platform::Platform,
-    player::Player,
-    weapon::{Weapon, WeaponState},
+use camera::Camera;
+use campaign::{Campaign, Manifest};
+use constants::{
+    animation::{PLAYER_SHEET, WEAPON_SHEET},
+    canvas::CANVAS,
 };
-use std::{cell::RefCell, rc::Rc};
+use gloo::{
+    events::{EventListener, EventListenerOptions},
+    net::http::Request,
+};
+use level::Level;
+use render::{canvas::CanvasRenderer, draw_aim_preview, draw_campaign, draw_reticle};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use timestep::FixedTimestep;
 use wasm_bindgen::prelude::*;
-use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement, js_sys};
 
-use events::{key_down, key_up, left_click, right_click};
+use components::position::Position;
+use events::{context_menu, key_down, key_up, mouse_down, mouse_move, mouse_up};
+use gamepad::GamepadState;
+use input::{InputEvent, InputQueue};
+use recordings::poll_recordings;
+use settings::{apply_settings, load_settings};
+use touch::{
+    Touches, draw_touch_controls, poll_touch, touch_cancel, touch_end, touch_move, touch_start,
+};
+use viewport::fit_canvas;
 
-mod components;
-mod constants;
+pub mod animation;
+pub mod bindings;
+pub mod camera;
+pub mod campaign;
+pub mod components;
+pub mod constants;
 mod events;
-mod sprites;
+mod gamepad;
+pub mod input;
+pub mod level;
+mod recordings;
+pub mod render;
+pub mod replay;
+mod settings;
+pub mod sprites;
+pub mod teleport;
+pub mod tilemap;
+pub mod timestep;
+mod touch;
+mod viewport;
+pub mod world;
+
+const CAMPAIGN_URL: &str = "levels/campaign.ron";
 
 fn get_window() -> web_sys::Window {
     web_sys::window().expect("No global « window » object")
@@ -28,14 +63,95 @@ fn get_document() -> web_sys::Document {
         .expect("No global « document » object")
 }
 
-fn request_animation_frame(f: &Closure<dyn FnMut()>) {
+fn request_animation_frame(f: &Closure<dyn FnMut(f64)>) {
     get_window()
         .request_animation_frame(f.as_ref().unchecked_ref())
         .expect("« requestAnimationFrame » is not registered");
 }
 
+async fn fetch_text(url: &str) -> Result<String, JsValue> {
+    let response = Request::get(url)
+        .send()
+        .await
+        .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!(
+            "Could not fetch « {url} »: {}",
+            response.status()
+        )));
+    }
+
+    response
+        .text()
+        .await
+        .map_err(|error| JsValue::from_str(&error.to_string()))
+}
+
+async fn load_level(url: &str) -> Result<Level, JsValue> {
+    let source = fetch_text(url).await?;
+
+    let level = if url.ends_with(".json") {
+        Level::from_json(&source)
+    } else {
+        Level::from_ron(&source)
+    };
+
+    level.map_err(|error| JsValue::from_str(&format!("« {url} »: {error}")))
+}
+
+fn directory(url: &str) -> &str {
+    url.rsplit_once('/').map_or("", |(directory, _)| directory)
+}
+
+async fn load_campaign(url: &str) -> Result<Campaign, JsValue> {
+    let source = fetch_text(url).await?;
+    let manifest = Manifest::from_ron(&source)
+        .map_err(|error| JsValue::from_str(&format!("« {url} »: {error}")))?;
+
+    let directory = directory(url);
+    let mut levels = Vec::new();
+
+    for level in &manifest.levels {
+        levels.push(load_level(&format!("{directory}/{level}")).await?);
+    }
+
+    Campaign::new(levels, (js_sys::Math::random() * u32::MAX as f64) as u64)
+        .ok_or_else(|| JsValue::from_str(&format!("« {url} » has no levels")))
+}
+
+fn load_image(renderer: &mut CanvasRenderer, name: &str, url: &str) -> Result<(), JsValue> {
+    let image = HtmlImageElement::new()?;
+    image.set_src(url);
+    renderer.add_image(name.to_string(), image);
+
+    Ok(())
+}
+
+fn load_images(renderer: &mut CanvasRenderer, campaign: &Campaign) -> Result<(), JsValue> {
+    let directory = directory(CAMPAIGN_URL);
+
+    for tilemap in campaign
+        .levels()
+        .iter()
+        .filter_map(|level| level.tilemap.as_ref())
+    {
+        load_image(
+            renderer,
+            &tilemap.tileset,
+            &format!("{directory}/{}", tilemap.tileset),
+        )?;
+    }
+
+    for sheet in [&*PLAYER_SHEET, &*WEAPON_SHEET] {
+        load_image(renderer, sheet.image(), sheet.image())?;
+    }
+
+    Ok(())
+}
+
 #[wasm_bindgen(start)]
-pub fn run() -> Result<(), JsValue> {
+pub async fn run() -> Result<(), JsValue> {
     let window = get_window();
     let document = get_document();
 
@@ -44,9 +160,6 @@ pub fn run() -> Result<(), JsValue> {
         .unwrap()
         .dyn_into::<HtmlCanvasElement>()?;
 
-    canvas.set_width(CANVAS.width() as u32);
-    canvas.set_height(CANVAS.height() as u32);
-
     let ctx = canvas
         .get_context("2d")?
         .unwrap()
@@ -55,73 +168,161 @@ pub fn run() -> Result<(), JsValue> {
     let f = Rc::new(RefCell::new(None));
     let g = f.clone();
 
-    let mut player = Player::new(
-        40.0,
-        20.0,
-        PLAYER.sprite().width(),
-        PLAYER.sprite().height(),
-        Some(HitBox::new(
-            PLAYER.sprite().width() + WEAPON.sprite().width(),
-            PLAYER.sprite().height(),
-        )),
-        "blue".to_string(),
-    );
-
-    let mut weapon = Weapon::new(
-        40.0,
-        20.0,
-        WEAPON.sprite().width(),
-        WEAPON.sprite().height(),
-        None,
-        "red".to_string(),
-    );
-
-    let platforms = vec![
-        Platform::new(500.0, 500.0, 300.0, 150.0, None, "orange".to_string()),
-        Platform::new(200.0, 200.0, 400.0, 100.0, None, "purple".to_string()),
-    ];
-
-    *g.borrow_mut() = Some(Closure::new(move || {
-        ctx.clear_rect(0.0, 0.0, CANVAS.width(), CANVAS.height());
-        ctx.set_fill_style_str("lightgrey");
-        ctx.fill_rect(0.0, 0.0, CANVAS.width(), CANVAS.height());
-
-        for platform in &platforms {
-            platform.draw(&ctx);
+    let mut campaign = load_campaign(CAMPAIGN_URL).await?;
+    let mut timestep = FixedTimestep::new();
+    let mut renderer = CanvasRenderer::new(ctx);
+    load_images(&mut renderer, &campaign)?;
+    fit_canvas(&window, &canvas, &mut renderer)?;
+    let mut gamepad = GamepadState::new();
+    let mut cursor = Position::new(0.0, 0.0);
+    let mut camera = Camera::new(CANVAS.width(), CANVAS.height());
+    camera.snap(campaign.world());
+
+    let queue = Rc::new(RefCell::new(InputQueue::new()));
+    let touches = Rc::new(RefCell::new(Touches::new()));
+
+    let resized = Rc::new(Cell::new(false));
+
+    let loop_queue = queue.clone();
+    let loop_touches = touches.clone();
+    let (loop_window, loop_canvas, loop_resized) =
+        (window.clone(), canvas.clone(), resized.clone());
+
+    *g.borrow_mut() = Some(Closure::new(move |now: f64| {
+        if loop_resized.replace(false)
+            && let Err(error) = fit_canvas(&loop_window, &loop_canvas, &mut renderer)
+        {
+            gloo::console::error!(error);
         }
 
-        player.draw(&ctx);
-        player.apply_physics();
-        player.resolve_collisions(&platforms);
-        player.apply_keys();
-        player.apply_clicks(&mut weapon);
+        gamepad.poll(&loop_queue);
+        poll_touch(&loop_touches, &loop_queue, campaign.world(), &camera);
 
-        weapon.draw(&ctx);
+        let reticle = gamepad.reticle(campaign.world().player());
 
-        match weapon.state() {
-            WeaponState::Carried => weapon.follow_player(&player),
-            WeaponState::Thrown => {
-                weapon.apply_physics();
-                weapon.resolve_collisions(&platforms);
+        {
+            let mut queue = loop_queue.borrow_mut();
+
+            if let Some(reticle) = &reticle {
+                queue.push(InputEvent::Cursor(camera.to_screen(reticle)));
             }
-            WeaponState::Stuck(_) => {}
+
+            queue.push(InputEvent::Move(
+                gamepad.move_x() + loop_touches.borrow().move_x(),
+            ));
+        }
+
+        apply_settings(&mut campaign);
+
+        for _ in 0..timestep.advance(now) {
+            let input = loop_queue.borrow_mut().drain();
+            let input = input.with_cursor(camera.to_world(input.cursor()));
+            campaign.step(&input);
+            cursor = *input.cursor();
+            camera.follow(campaign.world(), &cursor);
+        }
+
+        poll_recordings(&mut campaign);
+
+        draw_campaign(&mut renderer, &campaign, &camera, timestep.alpha());
+
+        if !campaign.playing() && campaign.summary().is_none() {
+            draw_aim_preview(
+                &mut renderer,
+                campaign.world(),
+                &cursor,
+                &camera,
+                timestep.alpha(),
+            );
+        }
+
+        if let Some(reticle) = &reticle {
+            draw_reticle(&mut renderer, &camera.to_screen(reticle));
         }
 
+        draw_touch_controls(&mut renderer, &loop_touches.borrow());
+
         request_animation_frame(f.borrow().as_ref().unwrap());
     }));
 
     request_animation_frame(g.borrow().as_ref().unwrap());
 
-    EventListener::new(&window, "keydown", key_down).forget();
-    EventListener::new(&window, "keyup", key_up).forget();
+    load_settings();
 
-    EventListener::new(&canvas, "click", left_click).forget();
+    EventListener::new(&window, "resize", move |_| resized.set(true)).forget();
+
+    let key_queue = queue.clone();
+    EventListener::new_with_options(
+        &window,
+        "keydown",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| key_down(&key_queue, event),
+    )
+    .forget();
+
+    let key_queue = queue.clone();
+    EventListener::new(&window, "keyup", move |event| key_up(&key_queue, event)).forget();
+
+    let (mouse_queue, mouse_canvas) = (queue.clone(), canvas.clone());
+    EventListener::new(&canvas, "mousedown", move |event| {
+        mouse_down(&mouse_queue, &mouse_canvas, event)
+    })
+    .forget();
+
+    let (mouse_queue, mouse_canvas) = (queue.clone(), canvas.clone());
+    EventListener::new(&window, "mousemove", move |event| {
+        mouse_move(&mouse_queue, &mouse_canvas, event)
+    })
+    .forget();
+
+    let mouse_queue = queue.clone();
+    EventListener::new(&window, "mouseup", move |event| {
+        mouse_up(&mouse_queue, event)
+    })
+    .forget();
 
     EventListener::new_with_options(
         &canvas,
         "contextmenu",
         EventListenerOptions::enable_prevent_default(),
-        right_click,
+        context_menu,
+    )
+    .forget();
+
+    let (touch_queue, start_touches, touch_canvas) =
+        (queue.clone(), touches.clone(), canvas.clone());
+    EventListener::new_with_options(
+        &canvas,
+        "touchstart",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| touch_start(&start_touches, &touch_queue, &touch_canvas, event),
+    )
+    .forget();
+
+    let (move_touches, touch_canvas) = (touches.clone(), canvas.clone());
+    EventListener::new_with_options(
+        &canvas,
+        "touchmove",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| touch_move(&move_touches, &touch_canvas, event),
+    )
+    .forget();
+
+    let (touch_queue, end_touches, touch_canvas) = (queue.clone(), touches.clone(), canvas.clone());
+    EventListener::new_with_options(
+        &canvas,
+        "touchend",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| touch_end(&end_touches, &touch_queue, &touch_canvas, event),
+    )
+    .forget();
+
+    let (touch_queue, cancel_touches, touch_canvas) = (queue, touches, canvas.clone());
+    EventListener::new_with_options(
+        &canvas,
+        "touchcancel",
+        EventListenerOptions::enable_prevent_default(),
+        move |event| touch_cancel(&cancel_touches, &touch_queue, &touch_canvas, event),
     )
     .forget();
 
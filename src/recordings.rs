@@ -0,0 +1,51 @@
+use gloo::storage::{LocalStorage, Storage};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use wasm_bindgen::prelude::*;
+
+use crate::{campaign::Campaign, replay::Replay};
+
+const REPLAY_KEY: &str = "crimson-recall:replay";
+
+static PENDING_REPLAY: Lazy<Mutex<Option<Replay>>> = Lazy::new(|| Mutex::new(None));
+
+pub fn save_replay(replay: &Replay) {
+    if let Err(error) = LocalStorage::set(REPLAY_KEY, replay.to_json()) {
+        gloo::console::error!(format!("Could not save replay: {error}"));
+    }
+}
+
+pub fn poll_recordings(campaign: &mut Campaign) {
+    if let Some(replay) = campaign.take_finished_recording() {
+        save_replay(&replay);
+    }
+
+    let Some(replay) = PENDING_REPLAY
+        .lock()
+        .ok()
+        .and_then(|mut pending| pending.take())
+    else {
+        return;
+    };
+
+    if let Err(error) = campaign.play(replay) {
+        gloo::console::error!(error.to_string());
+    }
+}
+
+#[wasm_bindgen]
+pub fn last_replay() -> Option<String> {
+    LocalStorage::get::<String>(REPLAY_KEY).ok()
+}
+
+#[wasm_bindgen]
+pub fn watch_replay(source: &str) -> Result<(), JsValue> {
+    let replay =
+        Replay::from_json(source).map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+    if let Ok(mut pending) = PENDING_REPLAY.lock() {
+        *pending = Some(replay);
+    }
+
+    Ok(())
+}
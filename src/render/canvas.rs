@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use web_sys::{CanvasRenderingContext2d, HtmlImageElement};
+
+use super::{Rect, Renderer};
+
+pub struct CanvasRenderer {
+    ctx: CanvasRenderingContext2d,
+    images: HashMap<String, HtmlImageElement>,
+}
+
+impl CanvasRenderer {
+    pub fn new(ctx: CanvasRenderingContext2d) -> Self {
+        Self {
+            ctx,
+            images: HashMap::new(),
+        }
+    }
+
+    pub fn add_image(&mut self, name: String, image: HtmlImageElement) {
+        self.images.insert(name, image);
+    }
+
+    pub fn set_scale(&mut self, x: f64, y: f64) {
+        let _ = self.ctx.set_transform(x, 0.0, 0.0, y, 0.0, 0.0);
+    }
+}
+
+impl Renderer for CanvasRenderer {
+    fn fill_rect(&mut self, rect: Rect, color: &str) {
+        self.ctx.set_fill_style_str(color);
+        self.ctx
+            .fill_rect(rect.x(), rect.y(), rect.width(), rect.height());
+    }
+
+    fn push_transform(&mut self, x: f64, y: f64, angle: f64) {
+        self.ctx.save();
+        let _ = self.ctx.translate(x, y);
+        let _ = self.ctx.rotate(angle);
+    }
+
+    fn pop_transform(&mut self) {
+        self.ctx.restore();
+    }
+
+    fn push_mirror(&mut self, axis_x: f64) {
+        self.ctx.save();
+        let _ = self.ctx.translate(axis_x * 2.0, 0.0);
+        let _ = self.ctx.scale(-1.0, 1.0);
+    }
+
+    fn image_ready(&self, image: &str) -> bool {
+        self.images
+            .get(image)
+            .is_some_and(|image| image.complete() && image.natural_width() > 0)
+    }
+
+    fn draw_image(&mut self, image: &str, source: Rect, destination: Rect) {
+        let Some(image) = self.images.get(image) else {
+            return;
+        };
+
+        if !image.complete() {
+            return;
+        }
+
+        let _ = self
+            .ctx
+            .draw_image_with_html_image_element_and_sw_and_sh_and_dx_and_dy_and_dw_and_dh(
+                image,
+                source.x(),
+                source.y(),
+                source.width(),
+                source.height(),
+                destination.x(),
+                destination.y(),
+                destination.width(),
+                destination.height(),
+            );
+    }
+
+    fn draw_text(&mut self, text: &str, x: f64, y: f64, font: &str, color: &str) {
+        self.ctx.set_font(font);
+        self.ctx.set_fill_style_str(color);
+        let _ = self.ctx.fill_text(text, x, y);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    bindings::Action,
+    camera::Camera,
+    campaign::Campaign,
+    components::{collision::box_x, position::Position},
+    constants::{canvas::CANVAS, controls::BINDINGS},
+    sprites::{Sprite, weapon::WeaponState},
+    teleport::TeleportResult,
+    world::World,
+};
+
+pub mod canvas;
+pub mod recording;
+
+const PREVIEW_TICKS: usize = 240;
+const PREVIEW_SPACING: usize = 6;
+const PREVIEW_DOT: f64 = 4.0;
+const OUT_OF_BOUNDS: &str = "#222";
+const CHARGE_BAR_WIDTH: f64 = 60.0;
+const CHARGE_BAR_HEIGHT: f64 = 6.0;
+const CHARGE_BAR_OFFSET: f64 = 14.0;
+const TELEPORT_FLASH_MARGIN: f64 = 6.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl Rect {
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+}
+
+pub trait Renderer {
+    fn fill_rect(&mut self, rect: Rect, color: &str);
+
+    fn push_transform(&mut self, x: f64, y: f64, angle: f64);
+
+    fn pop_transform(&mut self);
+
+    fn push_mirror(&mut self, axis_x: f64);
+
+    fn draw_image(&mut self, image: &str, source: Rect, destination: Rect);
+
+    fn image_ready(&self, _: &str) -> bool {
+        true
+    }
+
+    fn draw_text(&mut self, text: &str, x: f64, y: f64, font: &str, color: &str);
+}
+
+pub fn draw_world(renderer: &mut dyn Renderer, world: &World, alpha: f64) {
+    let bounds = world.bounds();
+
+    renderer.fill_rect(
+        Rect::new(bounds.x, bounds.y, bounds.width, bounds.height),
+        world.background(),
+    );
+
+    for platform in world.level_platforms() {
+        platform.draw(renderer, alpha);
+    }
+
+    if let Some(tilemap) = world.tilemap() {
+        if renderer.image_ready(tilemap.tileset()) {
+            for (source, destination) in tilemap.sprites() {
+                renderer.draw_image(tilemap.tileset(), *source, *destination);
+            }
+        } else {
+            for platform in world.tile_platforms() {
+                platform.draw(renderer, alpha);
+            }
+        }
+    }
+
+    if let Some(goal) = world.goal() {
+        goal.draw(renderer, alpha);
+    }
+
+    world.player().draw(renderer, alpha);
+    world.weapon().draw(renderer, alpha);
+
+    draw_teleport_feedback(renderer, world, alpha);
+    draw_charge_bar(renderer, world, alpha);
+}
+
+fn draw_teleport_feedback(renderer: &mut dyn Renderer, world: &World, alpha: f64) {
+    let (sprite, color): (&dyn Sprite, &str) = match world.teleport() {
+        Some(TeleportResult::Nudged) => (world.player(), "rgba(255, 255, 255, 0.35)"),
+        Some(TeleportResult::Blocked) => (world.weapon(), "rgba(220, 20, 60, 0.5)"),
+        Some(TeleportResult::Ok) | None => return,
+    };
+
+    let position = sprite.interpolated_position(alpha);
+
+    renderer.fill_rect(
+        Rect::new(
+            position.x() - TELEPORT_FLASH_MARGIN,
+            position.y() - TELEPORT_FLASH_MARGIN,
+            sprite.width() + TELEPORT_FLASH_MARGIN * 2.0,
+            sprite.height() + TELEPORT_FLASH_MARGIN * 2.0,
+        ),
+        color,
+    );
+}
+
+fn draw_charge_bar(renderer: &mut dyn Renderer, world: &World, alpha: f64) {
+    let player = world.player();
+
+    let Some(charge) = player.charge() else {
+        return;
+    };
+
+    let position = player.interpolated_position(alpha);
+    let x = position.x() + player.width() / 2.0 - CHARGE_BAR_WIDTH / 2.0;
+    let y = position.y() - CHARGE_BAR_OFFSET;
+
+    renderer.fill_rect(
+        Rect::new(x, y, CHARGE_BAR_WIDTH, CHARGE_BAR_HEIGHT),
+        "rgba(0, 0, 0, 0.4)",
+    );
+    renderer.fill_rect(
+        Rect::new(x, y, CHARGE_BAR_WIDTH * charge, CHARGE_BAR_HEIGHT),
+        "crimson",
+    );
+}
+
+pub fn draw_campaign(
+    renderer: &mut dyn Renderer,
+    campaign: &Campaign,
+    camera: &Camera,
+    alpha: f64,
+) {
+    let world = campaign.world();
+    let view = camera.interpolated_position(alpha);
+
+    renderer.fill_rect(
+        Rect::new(0.0, 0.0, CANVAS.width(), CANVAS.height()),
+        OUT_OF_BOUNDS,
+    );
+
+    renderer.push_transform(-view.x(), -view.y(), 0.0);
+    draw_world(renderer, world, alpha);
+    renderer.pop_transform();
+
+    renderer.draw_text(
+        &format!(
+            "{}/{} · {} · {:.1}s{}",
+            campaign.current() + 1,
+            campaign.level_count(),
+            world.name(),
+            world.stats().time(),
+            if campaign.playing() { " · Replay" } else { "" }
+        ),
+        16.0,
+        28.0,
+        "18px sans-serif",
+        "black",
+    );
+
+    let Some(summary) = campaign.summary() else {
+        return;
+    };
+
+    renderer.fill_rect(
+        Rect::new(0.0, 0.0, CANVAS.width(), CANVAS.height()),
+        "rgba(0, 0, 0, 0.6)",
+    );
+
+    let title = if summary.last() {
+        "Campaign complete!".to_string()
+    } else {
+        format!("{} complete!", summary.name())
+    };
+    let lines = [
+        format!("Time: {:.2}s", summary.time()),
+        format!("Throws: {}", summary.throws()),
+        format!("Teleports: {}", summary.teleports()),
+        retry_hint(),
+    ];
+
+    let x = CANVAS.width() / 2.0 - 150.0;
+    let y = CANVAS.height() / 2.0 - 80.0;
+
+    renderer.draw_text(&title, x, y, "bold 32px sans-serif", "white");
+
+    for (index, line) in lines.iter().enumerate() {
+        renderer.draw_text(
+            line,
+            x,
+            y + 48.0 + index as f64 * 28.0,
+            "20px sans-serif",
+            "white",
+        );
+    }
+}
+
+fn retry_hint() -> String {
+    let hint = BINDINGS
+        .lock()
+        .ok()
+        .and_then(|bindings| bindings.hint(Action::Restart));
+
+    match hint {
+        Some(hint) => format!("Press {hint} to retry"),
+        None => "Bind Restart to retry".to_string(),
+    }
+}
+
+pub fn draw_reticle(renderer: &mut dyn Renderer, reticle: &Position) {
+    renderer.fill_rect(
+        Rect::new(reticle.x() - 10.0, reticle.y() - 1.0, 20.0, 2.0),
+        "black",
+    );
+    renderer.fill_rect(
+        Rect::new(reticle.x() - 1.0, reticle.y() - 10.0, 2.0, 20.0),
+        "black",
+    );
+}
+
+pub fn draw_aim_preview(
+    renderer: &mut dyn Renderer,
+    world: &World,
+    cursor: &Position,
+    camera: &Camera,
+    alpha: f64,
+) {
+    let weapon = world.weapon();
+
+    if !matches!(weapon.state(), WeaponState::Carried) {
+        return;
+    }
+
+    let view = camera.interpolated_position(alpha);
+    let bounds = world.bounds();
+
+    let points = weapon.trajectory(
+        cursor.x(),
+        cursor.y(),
+        world.throw_power(cursor),
+        world.gravity(),
+        PREVIEW_TICKS,
+    );
+
+    renderer.push_transform(-view.x(), -view.y(), 0.0);
+
+    for point in points.iter().step_by(PREVIEW_SPACING) {
+        let x = point.x() + weapon.width() / 2.0;
+        let y = point.y() + weapon.height() / 2.0;
+
+        let outside = x < bounds.x || x > bounds.x + bounds.width || y > bounds.y + bounds.height;
+        let blocked = world
+            .grid()
+            .query(x, y, x, y)
+            .into_iter()
+            .filter_map(|index| world.platforms().get(index))
+            .any(|platform| {
+                let left = box_x(platform, platform.position());
+                let top = platform.position().y();
+                let hit_box = platform.collision_box();
+
+                x >= left && x <= left + hit_box.width() && y >= top && y <= top + hit_box.height()
+            });
+
+        if outside || blocked {
+            break;
+        }
+
+        renderer.fill_rect(
+            Rect::new(
+                x - PREVIEW_DOT / 2.0,
+                y - PREVIEW_DOT / 2.0,
+                PREVIEW_DOT,
+                PREVIEW_DOT,
+            ),
+            "rgba(0, 0, 0, 0.4)",
+        );
+    }
+
+    renderer.pop_transform();
+}
@@ -0,0 +1,98 @@
+use super::{Rect, Renderer};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    FillRect {
+        rect: Rect,
+        color: String,
+    },
+    PushTransform {
+        x: f64,
+        y: f64,
+        angle: f64,
+    },
+    PopTransform,
+    PushMirror {
+        axis_x: f64,
+    },
+    DrawImage {
+        image: String,
+        source: Rect,
+        destination: Rect,
+    },
+    DrawText {
+        text: String,
+        x: f64,
+        y: f64,
+        font: String,
+        color: String,
+    },
+}
+
+#[derive(Default)]
+pub struct RecordingRenderer {
+    commands: Vec<DrawCommand>,
+    pending_images: bool,
+}
+
+impl RecordingRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    pub fn set_pending_images(&mut self, pending_images: bool) {
+        self.pending_images = pending_images;
+    }
+
+    pub fn take_commands(&mut self) -> Vec<DrawCommand> {
+        std::mem::take(&mut self.commands)
+    }
+}
+
+impl Renderer for RecordingRenderer {
+    fn fill_rect(&mut self, rect: Rect, color: &str) {
+        self.commands.push(DrawCommand::FillRect {
+            rect,
+            color: color.to_string(),
+        });
+    }
+
+    fn push_transform(&mut self, x: f64, y: f64, angle: f64) {
+        self.commands
+            .push(DrawCommand::PushTransform { x, y, angle });
+    }
+
+    fn pop_transform(&mut self) {
+        self.commands.push(DrawCommand::PopTransform);
+    }
+
+    fn push_mirror(&mut self, axis_x: f64) {
+        self.commands.push(DrawCommand::PushMirror { axis_x });
+    }
+
+    fn draw_image(&mut self, image: &str, source: Rect, destination: Rect) {
+        self.commands.push(DrawCommand::DrawImage {
+            image: image.to_string(),
+            source,
+            destination,
+        });
+    }
+
+    fn image_ready(&self, _: &str) -> bool {
+        !self.pending_images
+    }
+
+    fn draw_text(&mut self, text: &str, x: f64, y: f64, font: &str, color: &str) {
+        self.commands.push(DrawCommand::DrawText {
+            text: text.to_string(),
+            x,
+            y,
+            font: font.to_string(),
+            color: color.to_string(),
+        });
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{constants::controls::ThrowMode, input::InputFrame, level::Level, world::World};
+
+const REPLAY_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct ReplayFrame {
+    repeat: u32,
+    input: InputFrame,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Replay {
+    version: u32,
+    level: String,
+    seed: u64,
+    #[serde(default)]
+    throw_mode: ThrowMode,
+    frames: Vec<ReplayFrame>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Parse(String),
+    Version(u32),
+    UnknownLevel(String),
+}
+
+impl Replay {
+    pub fn new(level: String, seed: u64, throw_mode: ThrowMode) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            level,
+            seed,
+            throw_mode,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn from_json(source: &str) -> Result<Self, ReplayError> {
+        let replay: Replay =
+            serde_json::from_str(source).map_err(|error| ReplayError::Parse(error.to_string()))?;
+
+        if replay.version != REPLAY_VERSION {
+            return Err(ReplayError::Version(replay.version));
+        }
+
+        Ok(replay)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    pub fn level(&self) -> &str {
+        &self.level
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn throw_mode(&self) -> ThrowMode {
+        self.throw_mode
+    }
+
+    pub fn ticks(&self) -> usize {
+        self.frames.iter().map(|frame| frame.repeat as usize).sum()
+    }
+
+    pub fn record(&mut self, input: &InputFrame) {
+        match self.frames.last_mut() {
+            Some(frame) if frame.input == *input => frame.repeat += 1,
+            _ => self.frames.push(ReplayFrame {
+                repeat: 1,
+                input: *input,
+            }),
+        }
+    }
+
+    pub fn frames(&self) -> impl Iterator<Item = &InputFrame> {
+        self.frames
+            .iter()
+            .flat_map(|frame| std::iter::repeat_n(&frame.input, frame.repeat as usize))
+    }
+
+    pub fn simulate(&self, level: &Level) -> Result<World, ReplayError> {
+        if level.name != self.level {
+            return Err(ReplayError::UnknownLevel(self.level.clone()));
+        }
+
+        let mut world = World::from_level_seeded(level, self.seed);
+        world.set_throw_mode(self.throw_mode);
+
+        for input in self.frames() {
+            world.step(input);
+        }
+
+        Ok(world)
+    }
+}
+
+pub struct Playback {
+    replay: Replay,
+    frame: usize,
+    repeat: u32,
+}
+
+impl Playback {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            frame: 0,
+            repeat: 0,
+        }
+    }
+
+    pub fn replay(&self) -> &Replay {
+        &self.replay
+    }
+
+    pub fn next_frame(&mut self) -> Option<InputFrame> {
+        let frame = self.replay.frames.get(self.frame)?;
+        let input = frame.input;
+
+        self.repeat += 1;
+
+        if self.repeat >= frame.repeat {
+            self.frame += 1;
+            self.repeat = 0;
+        }
+
+        Some(input)
+    }
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::Parse(error) => write!(f, "Could not parse replay: {error}"),
+            ReplayError::Version(version) => write!(
+                f,
+                "Replay version {version} is not supported (expected {REPLAY_VERSION})"
+            ),
+            ReplayError::UnknownLevel(level) => write!(f, "Unknown replay level « {level} »"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
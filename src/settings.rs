@@ -0,0 +1,158 @@
+use gloo::storage::{LocalStorage, Storage};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    bindings::{Action, Binding, Bindings},
+    campaign::Campaign,
+    constants::controls::{BINDINGS, CAPTURE, DEADZONES, Deadzones, THROW_MODE, ThrowMode},
+};
+
+const BINDINGS_KEY: &str = "crimson-recall:bindings";
+const DEADZONES_KEY: &str = "crimson-recall:deadzones";
+const THROW_MODE_KEY: &str = "crimson-recall:throw-mode";
+
+pub fn load_settings() {
+    load_bindings();
+    load_deadzones();
+    load_throw_mode();
+}
+
+pub fn apply_settings(campaign: &mut Campaign) {
+    if let Ok(throw_mode) = THROW_MODE.lock() {
+        campaign.set_throw_mode(*throw_mode);
+    }
+}
+
+fn load_bindings() {
+    let Ok(stored) = LocalStorage::get::<Bindings>(BINDINGS_KEY) else {
+        return;
+    };
+
+    if let Ok(mut bindings) = BINDINGS.lock() {
+        *bindings = stored;
+    }
+}
+
+pub fn save_bindings(bindings: &Bindings) {
+    if let Err(error) = LocalStorage::set(BINDINGS_KEY, bindings) {
+        gloo::console::error!(format!("Could not save bindings: {error}"));
+    }
+}
+
+fn load_deadzones() {
+    let Ok(stored) = LocalStorage::get::<Deadzones>(DEADZONES_KEY) else {
+        return;
+    };
+
+    if let Ok(mut deadzones) = DEADZONES.lock() {
+        *deadzones = stored;
+    }
+}
+
+fn load_throw_mode() {
+    let Ok(stored) = LocalStorage::get::<ThrowMode>(THROW_MODE_KEY) else {
+        return;
+    };
+
+    if let Ok(mut throw_mode) = THROW_MODE.lock() {
+        *throw_mode = stored;
+    }
+}
+
+#[wasm_bindgen]
+pub fn rebind(action: &str, binding: &str) -> Result<(), JsValue> {
+    let action = action
+        .parse::<Action>()
+        .map_err(|e| JsValue::from_str(&e))?;
+    let binding = binding
+        .parse::<Binding>()
+        .map_err(|e| JsValue::from_str(&e))?;
+
+    let Ok(mut bindings) = BINDINGS.lock() else {
+        return Err(JsValue::from_str("Bindings are unavailable"));
+    };
+
+    bindings.rebind(action, binding);
+    save_bindings(&bindings);
+
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub fn capture_binding(action: &str) -> Result<(), JsValue> {
+    let action = action
+        .parse::<Action>()
+        .map_err(|e| JsValue::from_str(&e))?;
+
+    if let Ok(mut capture) = CAPTURE.lock() {
+        *capture = Some(action);
+    }
+
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub fn reset_bindings() {
+    LocalStorage::delete(BINDINGS_KEY);
+
+    if let Ok(mut bindings) = BINDINGS.lock() {
+        *bindings = Bindings::default();
+    }
+}
+
+#[wasm_bindgen]
+pub fn bindings() -> String {
+    let Ok(bindings) = BINDINGS.lock() else {
+        return String::new();
+    };
+
+    Action::ALL
+        .into_iter()
+        .map(|action| {
+            let keys = bindings
+                .bindings(action)
+                .iter()
+                .map(Binding::to_string)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{action:?}: {keys}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[wasm_bindgen]
+pub fn set_deadzones(movement: f64, aim: f64) -> Result<(), JsValue> {
+    if !(0.0..1.0).contains(&movement) || !(0.0..1.0).contains(&aim) {
+        return Err(JsValue::from_str("Deadzones must be within [0, 1)"));
+    }
+
+    let deadzones = Deadzones { movement, aim };
+
+    if let Ok(mut stored) = DEADZONES.lock() {
+        *stored = deadzones;
+    }
+
+    if let Err(error) = LocalStorage::set(DEADZONES_KEY, deadzones) {
+        gloo::console::error!(format!("Could not save deadzones: {error}"));
+    }
+
+    Ok(())
+}
+
+#[wasm_bindgen]
+pub fn set_throw_mode(mode: &str) -> Result<(), JsValue> {
+    let mode = mode
+        .parse::<ThrowMode>()
+        .map_err(|e| JsValue::from_str(&e))?;
+
+    if let Ok(mut throw_mode) = THROW_MODE.lock() {
+        *throw_mode = mode;
+    }
+
+    if let Err(error) = LocalStorage::set(THROW_MODE_KEY, mode) {
+        gloo::console::error!(format!("Could not save throw mode: {error}"));
+    }
+
+    Ok(())
+}
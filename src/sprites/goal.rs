@@ -0,0 +1,56 @@
+use crate::{
+    components::{grid::SpatialGrid, hit_box::HitBox, position::Position},
+    level::Area,
+};
+
+use super::{Sprite, platform::Platform};
+
+pub struct Goal {
+    position: Position,
+    width: f64,
+    height: f64,
+    collision_box: HitBox,
+    color: String,
+}
+
+impl Goal {
+    pub fn new(x: f64, y: f64, width: f64, height: f64, color: String) -> Self {
+        Self {
+            position: Position::new(x, y),
+            width,
+            height,
+            collision_box: HitBox::new(width, height),
+            color,
+        }
+    }
+}
+
+impl Sprite for Goal {
+    fn apply_physics(&mut self, _: f64, _: &Area) {}
+
+    fn resolve_collisions(&mut self, _: &[Platform], _: &SpatialGrid) {}
+
+    fn position(&self) -> &Position {
+        &self.position
+    }
+
+    fn previous_position(&self) -> &Position {
+        &self.position
+    }
+
+    fn width(&self) -> f64 {
+        self.width
+    }
+
+    fn height(&self) -> f64 {
+        self.height
+    }
+
+    fn collision_box(&self) -> &HitBox {
+        &self.collision_box
+    }
+
+    fn color(&self) -> &str {
+        &self.color
+    }
+}
@@ -1,8 +1,12 @@
 use platform::Platform;
-use web_sys::CanvasRenderingContext2d;
 
-use crate::components::{hit_box::HitBox, position::Position};
+use crate::{
+    components::{grid::SpatialGrid, hit_box::HitBox, position::Position},
+    level::Area,
+    render::{Rect, Renderer},
+};
 
+pub mod goal;
 pub mod platform;
 pub mod player;
 pub mod weapon;
@@ -15,13 +19,29 @@ pub trait Sprite {
     fn collision_box(&self) -> &HitBox;
     fn color(&self) -> &str;
 
-    fn draw(&self, ctx: &CanvasRenderingContext2d) {
-        ctx.set_fill_style_str(&self.color());
-        let pos = self.position();
-        ctx.fill_rect(pos.x(), pos.y(), self.width(), self.height());
+    fn angle(&self) -> f64 {
+        0.0
     }
 
-    fn apply_physics(&mut self);
+    fn interpolated_position(&self, alpha: f64) -> Position {
+        let previous = self.previous_position();
+        let current = self.position();
 
-    fn resolve_collisions(&mut self, sprites: &Vec<Platform>);
+        Position::new(
+            previous.x() + (current.x() - previous.x()) * alpha,
+            previous.y() + (current.y() - previous.y()) * alpha,
+        )
+    }
+
+    fn draw(&self, renderer: &mut dyn Renderer, alpha: f64) {
+        let pos = self.interpolated_position(alpha);
+        renderer.fill_rect(
+            Rect::new(pos.x(), pos.y(), self.width(), self.height()),
+            self.color(),
+        );
+    }
+
+    fn apply_physics(&mut self, gravity: f64, bounds: &Area);
+
+    fn resolve_collisions(&mut self, platforms: &[Platform], grid: &SpatialGrid);
 }
@@ -1,4 +1,8 @@
-use crate::components::{hit_box::HitBox, position::Position};
+use crate::{
+    components::{grid::SpatialGrid, hit_box::HitBox, position::Position},
+    level::{Area, Material},
+    render::{Rect, Renderer},
+};
 
 use super::Sprite;
 
@@ -9,8 +13,13 @@ pub struct Platform {
     height: f64,
     collision_box: HitBox,
     color: String,
+    material: Material,
 }
 
+const STRIPE_WIDTH: f64 = 4.0;
+const STRIPE_SPACING: f64 = 16.0;
+const BOUNCY_BAND: f64 = 6.0;
+
 impl Platform {
     pub fn new(
         x: f64,
@@ -19,6 +28,7 @@ impl Platform {
         height: f64,
         collision_box: Option<HitBox>,
         color: String,
+        material: Material,
     ) -> Self {
         Self {
             position: Position::new(x, y),
@@ -30,14 +40,87 @@ impl Platform {
                 None => HitBox::new(width, height),
             },
             color,
+            material,
+        }
+    }
+
+    pub fn material(&self) -> Material {
+        self.material
+    }
+
+    fn draw_stripes(&self, renderer: &mut dyn Renderer, color: &str, vertical: bool) {
+        let length = if vertical { self.width } else { self.height };
+        let mut offset = (STRIPE_SPACING - STRIPE_WIDTH) / 2.0;
+
+        while offset < length {
+            let size = STRIPE_WIDTH.min(length - offset);
+
+            renderer.fill_rect(
+                if vertical {
+                    Rect::new(
+                        self.position.x() + offset,
+                        self.position.y(),
+                        size,
+                        self.height,
+                    )
+                } else {
+                    Rect::new(
+                        self.position.x(),
+                        self.position.y() + offset,
+                        self.width,
+                        size,
+                    )
+                },
+                color,
+            );
+
+            offset += STRIPE_SPACING;
         }
     }
 }
 
 impl Sprite for Platform {
-    fn apply_physics(&mut self) {}
+    fn draw(&self, renderer: &mut dyn Renderer, _: f64) {
+        let area = Rect::new(
+            self.position.x(),
+            self.position.y(),
+            self.width,
+            self.height,
+        );
+
+        renderer.fill_rect(area, &self.color);
+
+        match self.material {
+            Material::Sticky => {}
+            Material::Bouncy => renderer.fill_rect(
+                Rect::new(
+                    self.position.x(),
+                    self.position.y(),
+                    self.width,
+                    BOUNCY_BAND.min(self.height),
+                ),
+                "limegreen",
+            ),
+            Material::Metal => self.draw_stripes(renderer, "rgba(220, 220, 230, 0.6)", true),
+            Material::Glass => {
+                renderer.fill_rect(area, "rgba(200, 235, 255, 0.6)");
+                renderer.fill_rect(
+                    Rect::new(
+                        self.position.x(),
+                        self.position.y(),
+                        STRIPE_WIDTH.min(self.width),
+                        self.height,
+                    ),
+                    "rgba(255, 255, 255, 0.8)",
+                );
+            }
+            Material::NoTeleport => self.draw_stripes(renderer, "rgba(148, 0, 211, 0.6)", false),
+        }
+    }
+
+    fn apply_physics(&mut self, _: f64, _: &Area) {}
 
-    fn resolve_collisions(&mut self, _: &Vec<Platform>) {}
+    fn resolve_collisions(&mut self, _: &[Platform], _: &SpatialGrid) {}
 
     fn position(&self) -> &Position {
         &self.position
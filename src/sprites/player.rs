@@ -1,16 +1,22 @@
 use crate::{
+    animation::{Animator, Clip},
+    bindings::Action,
     components::{
-        collision::{Collision, collision_side, intersects},
+        collision::{
+            Collision, collision_side, contact, earliest_hit, half_extents, intersects, sweep,
+        },
+        grid::SpatialGrid,
         hit_box::HitBox,
         position::Position,
         velocity::Velocity,
     },
     constants::{
-        canvas::CANVAS,
-        controls::{KEYS, MOUSE},
-        player::PLAYER,
-        weapon::WEAPON,
+        animation::PLAYER_SHEET, controls::ThrowMode, player::PLAYER, time::TIME, weapon::WEAPON,
     },
+    input::InputFrame,
+    level::{Area, Material, Momentum},
+    render::{Rect, Renderer},
+    teleport::{TeleportResult, Terrain, solve},
 };
 
 use super::{
@@ -19,8 +25,21 @@ use super::{
     weapon::{StuckOn, Weapon, WeaponState},
 };
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Facing {
+    Left,
+    Right,
+}
+
+const JUMP_THRESHOLD: f64 = -60.0;
+const AIRBORNE_TICKS: u32 = 3;
+
 pub struct Player {
+    facing: Facing,
     jumping: bool,
+    airborne: u32,
+    momentum_x: f64,
+    charge: Option<u32>,
     position: Position,
     previous_position: Position,
     width: f64,
@@ -28,6 +47,7 @@ pub struct Player {
     collision_box: HitBox,
     velocity: Velocity,
     color: String,
+    animator: Animator,
 }
 
 impl Player {
@@ -40,7 +60,11 @@ impl Player {
         color: String,
     ) -> Self {
         Self {
+            facing: Facing::Right,
             jumping: false,
+            airborne: 0,
+            momentum_x: 0.0,
+            charge: None,
             position: Position::new(x, y),
             previous_position: Position::new(x, y),
             width,
@@ -51,21 +75,31 @@ impl Player {
             },
             velocity: Velocity::new(0.0, 0.0),
             color,
+            animator: Animator::new(),
         }
     }
 
-    pub fn apply_keys(&mut self) {
-        let Ok(keys) = KEYS.lock() else { return };
-
-        if keys.d.pressed {
+    pub fn apply_keys(&mut self, input: &InputFrame) {
+        if input.held(Action::MoveRight) {
             self.velocity.set_x(PLAYER.velocity().x());
-        } else if keys.a.pressed {
+        } else if input.held(Action::MoveLeft) {
             self.velocity.set_x(-PLAYER.velocity().x());
         } else {
-            self.velocity.set_x(0.0);
+            self.velocity
+                .set_x(input.move_x().clamp(-1.0, 1.0) * PLAYER.velocity().x());
         }
 
-        if keys.w.pressed && !self.jumping && self.velocity.y() == 0.0 {
+        if self.velocity.x() != 0.0 {
+            self.face(if self.velocity.x() < 0.0 {
+                Facing::Left
+            } else {
+                Facing::Right
+            });
+        }
+
+        let jump = input.held(Action::Jump) || input.just_pressed(Action::Jump);
+
+        if jump && !self.jumping && self.velocity.y() == 0.0 {
             self.jumping = true;
             self.velocity.set_y(PLAYER.velocity().jump());
         } else if self.velocity.y() == 0.0 {
@@ -73,106 +107,385 @@ impl Player {
         }
     }
 
-    pub fn apply_clicks(&mut self, weapon: &mut Weapon) {
-        let Ok(mut mouse) = MOUSE.lock() else { return };
+    pub fn apply_clicks(
+        &mut self,
+        input: &InputFrame,
+        weapon: &mut Weapon,
+        mode: ThrowMode,
+        air_teleport: Option<Momentum>,
+        terrain: &Terrain,
+    ) -> Option<TeleportResult> {
+        let target = input.cursor();
 
-        if !mouse.right.pressed {
-            return;
+        if input.just_pressed(Action::Recall)
+            && matches!(weapon.state(), WeaponState::Thrown | WeaponState::Stuck(_))
+        {
+            weapon.recall();
+            return None;
         }
 
         match weapon.state() {
             WeaponState::Carried => {
-                weapon.throw(mouse.right.x, mouse.right.y);
+                let power = match mode {
+                    ThrowMode::Distance if input.just_pressed(Action::Throw) => {
+                        weapon.distance_power(target.x(), target.y())
+                    }
+                    ThrowMode::Distance => return None,
+                    ThrowMode::Charge => {
+                        if input.just_pressed(Action::Throw) {
+                            self.charge = Some(0);
+                        }
+
+                        let ticks = self.charge?;
+
+                        if input.held(Action::Throw) && !input.just_released(Action::Throw) {
+                            self.charge = Some(ticks + 1);
+                            self.face_towards(target);
+                            return None;
+                        }
+
+                        self.charge = None;
+                        WEAPON.throw().charged_power(charge_ratio(ticks))
+                    }
+                };
+
+                self.face_towards(target);
+                weapon.follow_player(self);
+                weapon.throw(target.x(), target.y(), power);
                 weapon.set_state(WeaponState::Thrown);
+                self.animator.play(Clip::Throw);
                 self.collision_box.set_width(PLAYER.sprite().width());
+                self.align_collision_box();
+                None
+            }
+            WeaponState::Thrown => {
+                let momentum = air_teleport?;
+
+                if !input.just_pressed(Action::Throw) {
+                    return None;
+                }
+
+                let result = self.teleport_to_flying_weapon(weapon, momentum, terrain);
+
+                if result != TeleportResult::Blocked {
+                    weapon.set_state(WeaponState::Carried);
+                }
+
+                Some(result)
             }
-            WeaponState::Thrown => {}
+            WeaponState::Returning => None,
             WeaponState::Stuck(stuck_on) => {
-                self.teleport_to_weapon(weapon, stuck_on);
-                weapon.set_state(WeaponState::Carried);
-                self.collision_box
-                    .set_width(PLAYER.sprite().width() + WEAPON.sprite().width());
+                if !input.just_pressed(Action::Throw) {
+                    return None;
+                }
+
+                let result = self.teleport_to_weapon(weapon, stuck_on, terrain);
+
+                if result != TeleportResult::Blocked {
+                    weapon.set_state(WeaponState::Carried);
+                }
+
+                Some(result)
             }
         }
+    }
 
-        mouse.right.pressed = false;
+    pub fn facing(&self) -> Facing {
+        self.facing
     }
 
-    fn teleport_to_weapon(&mut self, weapon: &Weapon, stuck_on: &StuckOn) {
-        match stuck_on {
-            StuckOn::Left => {
-                self.position.set_x(weapon.position().x());
-                self.position
-                    .set_y(weapon.position().y() + weapon.height() - self.height);
+    pub fn weapon_offset(&self) -> f64 {
+        weapon_offset(self.width, self.facing)
+    }
+
+    fn face(&mut self, facing: Facing) {
+        self.facing = facing;
+        self.align_collision_box();
+    }
+
+    fn face_towards(&mut self, target: &Position) {
+        let center_x = self.position.x() + self.width / 2.0;
+
+        if target.x() < center_x {
+            self.face(Facing::Left);
+        } else if target.x() > center_x {
+            self.face(Facing::Right);
+        }
+    }
+
+    pub fn catch_weapon(&mut self) {
+        self.collision_box
+            .set_width(PLAYER.sprite().width() + WEAPON.sprite().width());
+        self.align_collision_box();
+    }
+
+    fn align_collision_box(&mut self) {
+        self.collision_box.set_offset_x(collision_offset(
+            self.width,
+            self.collision_box.width(),
+            self.facing,
+        ));
+    }
+
+    pub fn animator(&self) -> &Animator {
+        &self.animator
+    }
+
+    pub fn animate(&mut self) {
+        let locomotion = if self.velocity.y() < JUMP_THRESHOLD {
+            Clip::Jump
+        } else if self.airborne >= AIRBORNE_TICKS {
+            if self.velocity.y() < 0.0 {
+                Clip::Jump
+            } else {
+                Clip::Fall
             }
-            StuckOn::Right => {
-                self.position
-                    .set_x(weapon.position().x() - self.collision_box.width());
-                self.position
-                    .set_y(weapon.position().y() + weapon.height() - self.height);
+        } else if self.velocity.x() != 0.0 {
+            Clip::Run
+        } else {
+            Clip::Idle
+        };
+
+        self.animator.set_flipped(self.facing == Facing::Left);
+
+        self.animator.update(&PLAYER_SHEET, locomotion);
+    }
+
+    pub fn charge(&self) -> Option<f64> {
+        self.charge.map(charge_ratio)
+    }
+
+    fn teleport_to_weapon(
+        &mut self,
+        weapon: &Weapon,
+        stuck_on: &StuckOn,
+        terrain: &Terrain,
+    ) -> TeleportResult {
+        if weapon.surface() == Material::NoTeleport {
+            return TeleportResult::Blocked;
+        }
+
+        let handle = weapon.handle(stuck_on);
+        let handle_x = handle.x() - weapon.width() / 2.0;
+        let (_, half_height) = half_extents(weapon);
+        let center_y = weapon.position().y() + weapon.height() / 2.0;
+
+        let facing = match stuck_on {
+            StuckOn::Left => Facing::Right,
+            StuckOn::Right => Facing::Left,
+            StuckOn::Top | StuckOn::Bottom => self.facing,
+        };
+        let weapon_offset = weapon_offset(self.width, facing);
+
+        let destination = match stuck_on {
+            StuckOn::Left => Position::new(handle_x, handle.y() - self.height),
+            StuckOn::Right => Position::new(handle_x - self.width, handle.y() - self.height),
+            StuckOn::Top => Position::new(handle_x - weapon_offset, center_y - half_height),
+            StuckOn::Bottom => Position::new(
+                handle_x - weapon_offset,
+                center_y + half_height - self.height,
+            ),
+        };
+
+        self.teleport(destination, facing, terrain)
+    }
+
+    fn teleport_to_flying_weapon(
+        &mut self,
+        weapon: &Weapon,
+        momentum: Momentum,
+        terrain: &Terrain,
+    ) -> TeleportResult {
+        let destination = Position::new(
+            weapon.position().x() - self.weapon_offset(),
+            weapon.position().y() - WEAPON.sprite().y_offset(),
+        );
+
+        let result = self.teleport(destination, self.facing, terrain);
+
+        if result == TeleportResult::Blocked {
+            return result;
+        }
+
+        match momentum {
+            // Horizontal velocity is re-derived from input every tick, so keeping
+            // momentum only carries over the vertical speed and any launch momentum.
+            Momentum::Keep => {}
+            Momentum::Inherit => {
+                self.momentum_x = weapon.velocity().x();
+                self.velocity.set_y(weapon.velocity().y());
             }
-            StuckOn::Top => {
-                self.position
-                    .set_x(weapon.position().x() - self.collision_box.width());
-                self.position.set_y(weapon.position().y());
+            Momentum::Zero => {
+                self.momentum_x = 0.0;
+                self.velocity.set_y(0.0);
+            }
+        }
+
+        result
+    }
+
+    fn teleport(
+        &mut self,
+        destination: Position,
+        facing: Facing,
+        terrain: &Terrain,
+    ) -> TeleportResult {
+        let offset_x = collision_offset(
+            self.width,
+            PLAYER.sprite().width() + WEAPON.sprite().width(),
+            facing,
+        );
+
+        let area = Area {
+            x: destination.x() + offset_x,
+            y: destination.y(),
+            width: PLAYER.sprite().width() + WEAPON.sprite().width(),
+            height: self.collision_box.height(),
+        };
+
+        let Some((area, result)) = solve(&area, terrain) else {
+            return TeleportResult::Blocked;
+        };
+
+        self.animator.teleport(self.position);
+
+        self.facing = facing;
+        self.catch_weapon();
+
+        self.position = Position::new(area.x - offset_x, area.y);
+        self.previous_position = self.position;
+
+        result
+    }
+
+    fn apply_collision(&mut self, platform: &Platform, collision: &Collision) {
+        match collision {
+            Collision::Left => {
+                self.velocity.set_x(0.0);
+                self.momentum_x = 0.0;
+                self.position.set_x(
+                    platform.position().x()
+                        - self.collision_box.offset_x()
+                        - self.collision_box.width(),
+                );
+            }
+            Collision::Right => {
+                self.velocity.set_x(0.0);
+                self.momentum_x = 0.0;
+                self.position.set_x(
+                    platform.position().x() + platform.width() - self.collision_box.offset_x(),
+                );
             }
-            StuckOn::Bottom => {
+            Collision::Top => {
+                self.velocity.set_y(0.0);
+                self.airborne = 0;
+                self.momentum_x = 0.0;
                 self.position
-                    .set_x(weapon.position().x() - self.collision_box.width());
+                    .set_y(platform.position().y() - self.collision_box.height());
+            }
+            Collision::Bottom => {
+                self.velocity.set_y(0.0);
                 self.position
-                    .set_y(weapon.position().y() + weapon.height() - self.height);
+                    .set_y(platform.position().y() + platform.collision_box().height());
             }
         }
     }
 }
 
+fn weapon_offset(width: f64, facing: Facing) -> f64 {
+    match facing {
+        Facing::Right => WEAPON.sprite().x_offset(),
+        Facing::Left => width - WEAPON.sprite().x_offset() - WEAPON.sprite().width(),
+    }
+}
+
+fn collision_offset(width: f64, collision_width: f64, facing: Facing) -> f64 {
+    match facing {
+        Facing::Right => 0.0,
+        Facing::Left => width - collision_width,
+    }
+}
+
+fn charge_ratio(ticks: u32) -> f64 {
+    (ticks as f64 * TIME.dt() / WEAPON.throw().charge_time()).min(1.0)
+}
+
 impl Sprite for Player {
-    fn apply_physics(&mut self) {
+    fn draw(&self, renderer: &mut dyn Renderer, alpha: f64) {
+        if let Some((position, ghost)) = self.animator.ghost() {
+            PLAYER_SHEET.draw(
+                renderer,
+                &ghost,
+                Rect::new(position.x(), position.y(), self.width, self.height),
+            );
+        }
+
+        let pos = self.interpolated_position(alpha);
+        let destination = Rect::new(pos.x(), pos.y(), self.width, self.height);
+
+        if !PLAYER_SHEET.draw(renderer, &self.animator, destination) {
+            renderer.fill_rect(destination, &self.color);
+        }
+    }
+
+    fn apply_physics(&mut self, gravity: f64, bounds: &Area) {
+        self.airborne = self.airborne.saturating_add(1);
+
         self.previous_position.set_x(self.position.x());
         self.previous_position.set_y(self.position.y());
 
-        self.position.mutate_x(self.velocity.x());
-        self.position.mutate_y(self.velocity.y());
+        self.position
+            .mutate_x((self.velocity.x() + self.momentum_x) * TIME.dt());
+        self.position.mutate_y(self.velocity.y() * TIME.dt());
+
+        self.momentum_x *= (1.0 - PLAYER.velocity().air_drag() * TIME.dt()).max(0.0);
 
-        if self.position.y() + self.height + self.velocity.y() < CANVAS.height() {
-            self.velocity.mutate_y(CANVAS.gravity());
+        let offset_x = self.collision_box.offset_x();
+        let x = self.position.x().clamp(
+            bounds.x - offset_x,
+            (bounds.x + bounds.width - self.collision_box.width()).max(bounds.x) - offset_x,
+        );
+
+        if x != self.position.x() {
+            self.momentum_x = 0.0;
+            self.position.set_x(x);
+        }
+
+        let floor = bounds.y + bounds.height;
+
+        if self.position.y() + self.height + self.velocity.y() * TIME.dt() < floor {
+            self.velocity.mutate_y(gravity * TIME.dt());
         } else {
             self.velocity.set_y(0.0);
+            self.position.set_y(floor - self.height);
+            self.airborne = 0;
+            self.momentum_x = 0.0;
         }
     }
 
-    fn resolve_collisions(&mut self, platforms: &Vec<Platform>) {
-        for platform in platforms {
+    fn resolve_collisions(&mut self, platforms: &[Platform], grid: &SpatialGrid) {
+        let nearby = grid.nearby(platforms, self);
+
+        if let Some((platform, hit)) =
+            earliest_hit(nearby.iter().copied(), |platform| sweep(self, platform))
+        {
+            self.apply_collision(platform, hit.side());
+        }
+
+        for platform in nearby {
             if !intersects(self, platform) {
                 continue;
             }
 
             let Some(collision) = collision_side(self, platform) else {
+                if let Some(contact) = contact(self, platform) {
+                    self.position.mutate_x(contact.normal_x() * contact.depth());
+                    self.position.mutate_y(contact.normal_y() * contact.depth());
+                }
                 continue;
             };
 
-            match collision {
-                Collision::Left => {
-                    self.velocity.set_x(0.0);
-                    self.position
-                        .set_x(platform.position().x() - self.collision_box.width());
-                }
-                Collision::Right => {
-                    self.velocity.set_x(0.0);
-                    self.position
-                        .set_x(platform.position().x() + platform.width());
-                }
-                Collision::Top => {
-                    self.velocity.set_y(0.0);
-                    self.position
-                        .set_y(platform.position().y() - self.collision_box.height());
-                }
-                Collision::Bottom => {
-                    self.velocity.set_y(0.0);
-                    self.position
-                        .set_y(platform.position().y() + platform.collision_box().height());
-                }
-            }
+            self.apply_collision(platform, &collision);
         }
     }
 
@@ -1,15 +1,25 @@
 use crate::{
+    animation::{Animator, Clip},
     components::{
-        collision::{Collision, collision_side, intersects},
+        collision::{
+            Collision, collision_side, contact, earliest_hit, half_extents, intersects, sweep,
+            sweep_box,
+        },
+        grid::SpatialGrid,
         hit_box::HitBox,
         position::Position,
         velocity::Velocity,
     },
-    constants::{canvas::CANVAS, weapon::WEAPON},
+    constants::{animation::WEAPON_SHEET, material::MATERIAL, time::TIME, weapon::WEAPON},
+    level::{Area, Material},
+    render::{Rect, Renderer},
 };
 
 use super::{Sprite, platform::Platform, player::Player};
 
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum StuckOn {
     Left,
     Right,
@@ -17,10 +27,12 @@ pub enum StuckOn {
     Bottom,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum WeaponState {
     Carried,
     Thrown,
     Stuck(StuckOn),
+    Returning,
 }
 
 pub struct Weapon {
@@ -34,6 +46,8 @@ pub struct Weapon {
     collision_box: HitBox,
     velocity: Velocity,
     color: String,
+    animator: Animator,
+    surface: Material,
 }
 
 impl Weapon {
@@ -48,7 +62,7 @@ impl Weapon {
         Self {
             state: WeaponState::Carried,
             angle: 0.0,
-            angular_velocity: 0.01,
+            angular_velocity: 0.0,
             position: Position::new(x, y),
             previous_position: Position::new(x, y),
             width,
@@ -59,95 +73,384 @@ impl Weapon {
             },
             velocity: Velocity::new(0.0, 0.0),
             color,
+            animator: Animator::new(),
+            surface: Material::Sticky,
         }
     }
 
-    pub fn throw(&mut self, target_x: f64, target_y: f64) {
+    pub fn distance_power(&self, target_x: f64, target_y: f64) -> f64 {
+        let dx = target_x - self.position.x();
+        let dy = target_y - self.position.y();
+
+        (dx.hypot(dy) * WEAPON.throw().power_ratio()).min(WEAPON.throw().max_power())
+    }
+
+    pub fn throw_velocity(&self, target_x: f64, target_y: f64, power: f64) -> Velocity {
         let dx = target_x - self.position.x();
         let dy = target_y - self.position.y();
 
         let angle = dy.atan2(dx);
-        let distance = (dx.powi(2) + dy.powi(2)).sqrt();
 
-        let power = (distance / 10.0).min(20.0);
+        Velocity::new(angle.cos() * power, angle.sin() * power)
+    }
+
+    pub fn throw(&mut self, target_x: f64, target_y: f64, power: f64) {
+        self.velocity = self.throw_velocity(target_x, target_y, power);
+
+        let spin = (power - WEAPON.throw().spin_threshold()).max(0.0) * WEAPON.throw().spin_ratio();
+
+        self.angular_velocity = if self.velocity.x() < 0.0 { -spin } else { spin };
+
+        if self.angular_velocity == 0.0 {
+            self.align_with_velocity();
+        }
+    }
+
+    pub fn recall(&mut self) {
+        if matches!(self.state, WeaponState::Stuck(_)) {
+            self.velocity = Velocity::new(0.0, 0.0);
+        }
+
+        self.angular_velocity = 0.0;
+        self.state = WeaponState::Returning;
+    }
+
+    pub fn home(&mut self, player: &Player) {
+        self.previous_position = self.position;
+
+        let dx = player.position().x() + player.weapon_offset() - self.position.x();
+        let dy = player.position().y() + WEAPON.sprite().y_offset() - self.position.y();
+        let distance = dx.hypot(dy);
+
+        if distance <= WEAPON.recall().catch_distance() {
+            self.set_state(WeaponState::Carried);
+            self.follow_player(player);
+            return;
+        }
+
+        let speed = WEAPON.recall().speed();
+        let blend = (WEAPON.recall().steering() * TIME.dt()).min(1.0);
+
+        self.velocity
+            .set_x(self.velocity.x() + (dx / distance * speed - self.velocity.x()) * blend);
+        self.velocity
+            .set_y(self.velocity.y() + (dy / distance * speed - self.velocity.y()) * blend);
+
+        self.position.mutate_x(self.velocity.x() * TIME.dt());
+        self.position.mutate_y(self.velocity.y() * TIME.dt());
+
+        self.align_with_velocity();
+    }
+
+    fn align_with_velocity(&mut self) {
+        if self.velocity.x() != 0.0 || self.velocity.y() != 0.0 {
+            self.angle = self.velocity.y().atan2(self.velocity.x()) + FRAC_PI_2;
+        }
+    }
+
+    fn stick(&mut self, stuck_on: StuckOn, surface: Material) {
+        self.angular_velocity = 0.0;
+        self.surface = surface;
+        self.state = WeaponState::Stuck(stuck_on);
+    }
+
+    fn respond(&mut self, material: Material, collision: Collision) {
+        let (normal_x, normal_y) = match collision {
+            Collision::Left => (-1.0, 0.0),
+            Collision::Right => (1.0, 0.0),
+            Collision::Top => (0.0, -1.0),
+            Collision::Bottom => (0.0, 1.0),
+        };
+
+        let stuck_on = match collision {
+            Collision::Left => StuckOn::Right,
+            Collision::Right => StuckOn::Left,
+            Collision::Top => StuckOn::Bottom,
+            Collision::Bottom => StuckOn::Top,
+        };
+
+        let impact = self.velocity.x() * normal_x + self.velocity.y() * normal_y;
+
+        let (velocity_x, velocity_y) = match material {
+            Material::Sticky | Material::NoTeleport => {
+                self.velocity = Velocity::new(0.0, 0.0);
+                self.stick(stuck_on, material);
+                return;
+            }
+            // Glass is removed by `World::shatter_glass` before collisions are resolved.
+            Material::Glass => return,
+            Material::Bouncy if impact < 0.0 => {
+                let bounce = (1.0 + MATERIAL.restitution()) * impact;
+
+                (
+                    self.velocity.x() - bounce * normal_x,
+                    self.velocity.y() - bounce * normal_y,
+                )
+            }
+            Material::Bouncy => (self.velocity.x(), self.velocity.y()),
+            Material::Metal => {
+                let friction = (1.0 - MATERIAL.friction() * TIME.dt()).max(0.0);
+                let slide = impact.min(0.0);
+
+                (
+                    (self.velocity.x() - slide * normal_x) * friction,
+                    (self.velocity.y() - slide * normal_y) * friction,
+                )
+            }
+        };
+
+        self.velocity = Velocity::new(velocity_x, velocity_y);
+
+        if matches!(collision, Collision::Top)
+            && velocity_x.hypot(velocity_y) < MATERIAL.rest_speed()
+        {
+            self.velocity = Velocity::new(0.0, 0.0);
+            self.stick(StuckOn::Bottom, material);
+        }
+    }
+
+    fn apply_collision(&mut self, platform: &Platform, collision: Collision) {
+        match collision {
+            Collision::Left => self
+                .position
+                .set_x(platform.position().x() - self.collision_box.width()),
+            Collision::Right => self
+                .position
+                .set_x(platform.position().x() + platform.width()),
+            Collision::Top => self
+                .position
+                .set_y(platform.position().y() - self.collision_box.height()),
+            Collision::Bottom => self
+                .position
+                .set_y(platform.position().y() + platform.collision_box().height()),
+        }
+
+        self.respond(platform.material(), collision);
+    }
+
+    fn resolve_rotated_collisions(&mut self, platforms: &[&Platform]) {
+        let (half_width, half_height) = half_extents(self);
+        let offset_x = self.width / 2.0 - half_width;
+        let offset_y = self.height / 2.0 - half_height;
+
+        let start = Position::new(
+            self.previous_position.x() + offset_x,
+            self.previous_position.y() + offset_y,
+        );
+        let end = Position::new(self.position.x() + offset_x, self.position.y() + offset_y);
+
+        let start_time = earliest_hit(platforms.iter().copied(), |platform| {
+            sweep_box(&start, &end, half_width * 2.0, half_height * 2.0, platform)
+        })
+        .map_or(1.0, |(_, hit)| hit.time());
+
+        let distance = (end.x() - start.x()).hypot(end.y() - start.y()) * (1.0 - start_time);
+        let steps = (distance / half_width.min(half_height)).ceil().max(1.0) as usize;
+        let end = self.position;
+
+        for step in 0..=steps {
+            self.position = self.previous_position.lerp(
+                &end,
+                start_time + (1.0 - start_time) * step as f64 / steps as f64,
+            );
+
+            let Some((platform, contact)) = platforms
+                .iter()
+                .find_map(|platform| contact(self, *platform).map(|contact| (*platform, contact)))
+            else {
+                continue;
+            };
+
+            self.position.mutate_x(contact.normal_x() * contact.depth());
+            self.position.mutate_y(contact.normal_y() * contact.depth());
+            self.respond(platform.material(), contact.side());
+            return;
+        }
+
+        self.position = end;
+    }
+
+    pub fn handle(&self, stuck_on: &StuckOn) -> Position {
+        let center_x = self.position.x() + self.width / 2.0;
+        let center_y = self.position.y() + self.height / 2.0;
+
+        let axis_x = self.angle.sin() * self.height / 2.0;
+        let axis_y = -self.angle.cos() * self.height / 2.0;
+
+        let (normal_x, normal_y) = match stuck_on {
+            StuckOn::Left => (1.0, 0.0),
+            StuckOn::Right => (-1.0, 0.0),
+            StuckOn::Top => (0.0, 1.0),
+            StuckOn::Bottom => (0.0, -1.0),
+        };
+
+        let first = Position::new(center_x + axis_x, center_y + axis_y);
+        let second = Position::new(center_x - axis_x, center_y - axis_y);
 
-        self.velocity.set_x(angle.cos() * power);
-        self.velocity.set_y(angle.sin() * power);
+        let first_away = axis_x * normal_x + axis_y * normal_y;
+
+        if first_away > 0.0 || (first_away == 0.0 && first.y() >= second.y()) {
+            first
+        } else {
+            second
+        }
+    }
+
+    pub fn trajectory(
+        &self,
+        target_x: f64,
+        target_y: f64,
+        power: f64,
+        gravity: f64,
+        ticks: usize,
+    ) -> Vec<Position> {
+        let mut velocity = self.throw_velocity(target_x, target_y, power);
+        let mut position = self.position;
+
+        (0..ticks)
+            .map(|_| {
+                position.mutate_x(velocity.x() * TIME.dt());
+                position.mutate_y(velocity.y() * TIME.dt());
+                velocity.mutate_y(gravity * TIME.dt());
+                position
+            })
+            .collect()
     }
 
     pub fn follow_player(&mut self, player: &Player) {
+        self.previous_position
+            .set_x(player.previous_position().x() + player.weapon_offset());
+        self.previous_position
+            .set_y(player.previous_position().y() + WEAPON.sprite().y_offset());
         self.position
-            .set_x(player.position().x() + WEAPON.sprite().x_offset());
+            .set_x(player.position().x() + player.weapon_offset());
         self.position
             .set_y(player.position().y() + WEAPON.sprite().y_offset());
     }
 
+    pub fn animate(&mut self) {
+        let clip = match self.state {
+            WeaponState::Thrown | WeaponState::Returning => Clip::Throw,
+            WeaponState::Carried | WeaponState::Stuck(_) => Clip::Idle,
+        };
+
+        self.animator.update(&WEAPON_SHEET, clip);
+    }
+
+    pub fn surface(&self) -> Material {
+        self.surface
+    }
+
+    pub fn shatter(&mut self) {
+        self.velocity = Velocity::new(
+            self.velocity.x() * MATERIAL.glass_damping(),
+            self.velocity.y() * MATERIAL.glass_damping(),
+        );
+    }
+
+    pub fn velocity(&self) -> &Velocity {
+        &self.velocity
+    }
+
     pub fn state(&self) -> &WeaponState {
         &self.state
     }
 
     pub fn set_state(&mut self, state: WeaponState) {
+        if matches!(state, WeaponState::Carried) {
+            self.angle = 0.0;
+            self.angular_velocity = 0.0;
+        }
+
         self.state = state;
     }
 }
 
 impl Sprite for Weapon {
-    fn draw(&self, ctx: &web_sys::CanvasRenderingContext2d) {
-        match self.state {
-            WeaponState::Carried | WeaponState::Stuck(_) => {
-                ctx.save();
-
-                if ctx
-                    .translate(
-                        self.position.x() + self.width / 2.0,
-                        self.position.y() + self.height / 2.0,
-                    )
-                    .is_ok()
-                    && ctx.rotate(self.angle).is_ok()
-                {
-                    ctx.set_fill_style_str(&self.color());
-                    ctx.fill_rect(
-                        -self.width / 2.0,
-                        -self.height / 2.0,
-                        self.width,
-                        self.height,
-                    );
-                }
-
-                ctx.restore();
-            }
-            WeaponState::Thrown => {
-                ctx.set_fill_style_str(&self.color);
-                ctx.fill_rect(
-                    self.position.x(),
-                    self.position.y(),
-                    self.width,
-                    self.height,
-                );
-            }
+    fn draw(&self, renderer: &mut dyn Renderer, alpha: f64) {
+        let pos = self.interpolated_position(alpha);
+
+        renderer.push_transform(
+            pos.x() + self.width / 2.0,
+            pos.y() + self.height / 2.0,
+            self.angle,
+        );
+
+        let destination = Rect::new(
+            -self.width / 2.0,
+            -self.height / 2.0,
+            self.width,
+            self.height,
+        );
+
+        if !WEAPON_SHEET.draw(renderer, &self.animator, destination) {
+            renderer.fill_rect(destination, &self.color);
         }
+
+        renderer.pop_transform();
     }
 
-    fn apply_physics(&mut self) {
+    fn apply_physics(&mut self, gravity: f64, bounds: &Area) {
         self.previous_position.set_x(self.position.x());
         self.previous_position.set_y(self.position.y());
 
-        self.position.mutate_x(self.velocity.x());
-        self.position.mutate_y(self.velocity.y());
+        self.position.mutate_x(self.velocity.x() * TIME.dt());
+        self.position.mutate_y(self.velocity.y() * TIME.dt());
+
+        let (half_width, half_height) = half_extents(self);
+        let floor = bounds.y + bounds.height;
+        let bottom = self.position.y() + self.height / 2.0 + half_height;
 
-        if self.position.y() + self.height + self.velocity.y() < CANVAS.height() {
-            self.velocity.mutate_y(CANVAS.gravity());
+        if bottom + self.velocity.y() * TIME.dt() < floor {
+            self.velocity.mutate_y(gravity * TIME.dt());
         } else {
             self.velocity.set_y(0.0);
-            self.position.set_y(CANVAS.height() - self.height);
-            self.state = WeaponState::Stuck(StuckOn::Bottom);
+            self.position.set_y(floor - self.height / 2.0 - half_height);
+            self.stick(StuckOn::Bottom, Material::Sticky);
         }
 
-        self.angle = self.angular_velocity;
+        let center_x = self.position.x() + self.width / 2.0;
+
+        if center_x - half_width < bounds.x {
+            self.velocity.set_x(0.0);
+            self.position
+                .set_x(bounds.x + half_width - self.width / 2.0);
+            self.stick(StuckOn::Left, Material::Sticky);
+        } else if center_x + half_width > bounds.x + bounds.width {
+            self.velocity.set_x(0.0);
+            self.position
+                .set_x(bounds.x + bounds.width - half_width - self.width / 2.0);
+            self.stick(StuckOn::Right, Material::Sticky);
+        }
+
+        if !matches!(self.state, WeaponState::Thrown) {
+            return;
+        }
+
+        if self.angular_velocity == 0.0 {
+            self.align_with_velocity();
+        } else {
+            self.angle = (self.angle + self.angular_velocity * TIME.dt() + PI).rem_euclid(TAU) - PI;
+        }
     }
 
-    fn resolve_collisions(&mut self, platforms: &Vec<Platform>) {
-        for platform in platforms {
+    fn resolve_collisions(&mut self, platforms: &[Platform], grid: &SpatialGrid) {
+        let nearby = grid.nearby(platforms, self);
+
+        if self.angle != 0.0 {
+            self.resolve_rotated_collisions(&nearby);
+            return;
+        }
+
+        if let Some((platform, hit)) =
+            earliest_hit(nearby.iter().copied(), |platform| sweep(self, platform))
+        {
+            self.position = self.previous_position.lerp(&self.position, hit.time());
+            self.apply_collision(platform, *hit.side());
+            return;
+        }
+
+        for platform in nearby {
             if !intersects(self, platform) {
                 continue;
             }
@@ -156,32 +459,7 @@ impl Sprite for Weapon {
                 continue;
             };
 
-            match collision {
-                Collision::Left => {
-                    self.velocity.set_x(0.0);
-                    self.position
-                        .set_x(platform.position().x() - self.collision_box.width());
-                    self.state = WeaponState::Stuck(StuckOn::Right);
-                }
-                Collision::Right => {
-                    self.velocity.set_x(0.0);
-                    self.position
-                        .set_x(platform.position().x() + platform.width());
-                    self.state = WeaponState::Stuck(StuckOn::Left);
-                }
-                Collision::Top => {
-                    self.velocity.set_y(0.0);
-                    self.position
-                        .set_y(platform.position().y() - self.collision_box.height());
-                    self.state = WeaponState::Stuck(StuckOn::Bottom);
-                }
-                Collision::Bottom => {
-                    self.velocity.set_y(0.0);
-                    self.position
-                        .set_y(platform.position().y() + platform.collision_box().height());
-                    self.state = WeaponState::Stuck(StuckOn::Top);
-                }
-            }
+            self.apply_collision(platform, collision);
         }
     }
 
@@ -208,4 +486,91 @@ impl Sprite for Weapon {
     fn color(&self) -> &str {
         &self.color
     }
+
+    fn angle(&self) -> f64 {
+        self.angle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn thrown(velocity_x: f64, velocity_y: f64) -> Weapon {
+        let mut weapon = Weapon::new(100.0, 100.0, 30.0, 100.0, None, "red".to_string());
+        weapon.set_state(WeaponState::Thrown);
+        weapon.velocity = Velocity::new(velocity_x, velocity_y);
+        weapon
+    }
+
+    fn velocity(weapon: &Weapon) -> (f64, f64) {
+        (weapon.velocity.x(), weapon.velocity.y())
+    }
+
+    #[test]
+    fn bounces_off_each_side() {
+        let restitution = MATERIAL.restitution();
+
+        for (collision, incoming, outgoing) in [
+            (Collision::Left, (400.0, 50.0), (-400.0 * restitution, 50.0)),
+            (
+                Collision::Right,
+                (-400.0, 50.0),
+                (400.0 * restitution, 50.0),
+            ),
+            (Collision::Top, (50.0, 400.0), (50.0, -400.0 * restitution)),
+            (
+                Collision::Bottom,
+                (50.0, -400.0),
+                (50.0, 400.0 * restitution),
+            ),
+        ] {
+            let mut weapon = thrown(incoming.0, incoming.1);
+
+            weapon.respond(Material::Bouncy, collision);
+
+            let (x, y) = velocity(&weapon);
+            assert!((x - outgoing.0).abs() < 1e-9 && (y - outgoing.1).abs() < 1e-9);
+            assert_eq!(weapon.state(), &WeaponState::Thrown);
+        }
+    }
+
+    #[test]
+    fn bouncy_rest_sticks_on_top() {
+        let mut weapon = thrown(10.0, 20.0);
+
+        weapon.respond(Material::Bouncy, Collision::Top);
+
+        assert_eq!(weapon.state(), &WeaponState::Stuck(StuckOn::Bottom));
+        assert_eq!(weapon.surface(), Material::Bouncy);
+    }
+
+    #[test]
+    fn metal_slide_decays_to_stick() {
+        let mut weapon = thrown(500.0, 300.0);
+        let mut speeds = Vec::new();
+
+        while weapon.state() == &WeaponState::Thrown {
+            weapon.respond(Material::Metal, Collision::Top);
+            speeds.push(weapon.velocity.x());
+            assert!(speeds.len() < 1000, "weapon never came to rest");
+        }
+
+        assert!(speeds.windows(2).all(|pair| pair[1] < pair[0]));
+        assert_eq!(weapon.state(), &WeaponState::Stuck(StuckOn::Bottom));
+        assert_eq!(weapon.surface(), Material::Metal);
+        assert_eq!(velocity(&weapon), (0.0, 0.0));
+    }
+
+    #[test]
+    fn metal_wall_keeps_falling() {
+        let mut weapon = thrown(400.0, 200.0);
+
+        weapon.respond(Material::Metal, Collision::Left);
+
+        let (x, y) = velocity(&weapon);
+        assert_eq!(x, 0.0);
+        assert!(y > 0.0);
+        assert_eq!(weapon.state(), &WeaponState::Thrown);
+    }
 }
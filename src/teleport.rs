@@ -0,0 +1,174 @@
+use crate::{
+    components::grid::SpatialGrid,
+    constants::teleport::TELEPORT,
+    level::Area,
+    sprites::{Sprite, platform::Platform},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TeleportResult {
+    Ok,
+    Nudged,
+    Blocked,
+}
+
+pub struct Terrain<'a> {
+    pub platforms: &'a [Platform],
+    pub grid: &'a SpatialGrid,
+    pub bounds: &'a Area,
+}
+
+fn free(area: &Area, terrain: &Terrain) -> bool {
+    if !area.inside(terrain.bounds) {
+        return false;
+    }
+
+    terrain
+        .grid
+        .query(area.x, area.y, area.x + area.width, area.y + area.height)
+        .into_iter()
+        .filter_map(|index| terrain.platforms.get(index))
+        .all(|platform| {
+            !area.overlaps(&Area {
+                x: platform.position().x(),
+                y: platform.position().y(),
+                width: platform.collision_box().width(),
+                height: platform.collision_box().height(),
+            })
+        })
+}
+
+pub fn solve(area: &Area, terrain: &Terrain) -> Option<(Area, TeleportResult)> {
+    if free(area, terrain) {
+        return Some((*area, TeleportResult::Ok));
+    }
+
+    let steps = (TELEPORT.radius() / TELEPORT.step()) as i32;
+
+    let mut offsets: Vec<(f64, f64)> = (-steps..=steps)
+        .flat_map(|x| (-steps..=steps).map(move |y| (x, y)))
+        .map(|(x, y)| (x as f64 * TELEPORT.step(), y as f64 * TELEPORT.step()))
+        .filter(|(x, y)| x.hypot(*y) <= TELEPORT.radius())
+        .collect();
+
+    offsets.sort_by(|first, second| {
+        first
+            .0
+            .hypot(first.1)
+            .total_cmp(&second.0.hypot(second.1))
+            .then(first.1.total_cmp(&second.1))
+    });
+
+    offsets
+        .into_iter()
+        .map(|(x, y)| Area {
+            x: area.x + x,
+            y: area.y + y,
+            ..*area
+        })
+        .find(|candidate| free(candidate, terrain))
+        .map(|candidate| (candidate, TeleportResult::Nudged))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(x: f64, y: f64, width: f64, height: f64) -> Area {
+        Area {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    fn platform(x: f64, y: f64, width: f64, height: f64) -> Platform {
+        Platform::new(
+            x,
+            y,
+            width,
+            height,
+            None,
+            "gray".to_string(),
+            Default::default(),
+        )
+    }
+
+    fn resolve(
+        candidate: &Area,
+        platforms: &[Platform],
+        bounds: &Area,
+    ) -> Option<(f64, f64, TeleportResult)> {
+        let grid = SpatialGrid::new(64.0, platforms);
+        let terrain = Terrain {
+            platforms,
+            grid: &grid,
+            bounds,
+        };
+
+        solve(candidate, &terrain).map(|(area, result)| (area.x, area.y, result))
+    }
+
+    const BOUNDS: Area = Area {
+        x: 0.0,
+        y: 0.0,
+        width: 1024.0,
+        height: 576.0,
+    };
+
+    #[test]
+    fn free_space_is_ok() {
+        let platforms = [platform(600.0, 0.0, 40.0, 576.0)];
+
+        assert_eq!(
+            resolve(&area(100.0, 200.0, 130.0, 200.0), &platforms, &BOUNDS),
+            Some((100.0, 200.0, TeleportResult::Ok))
+        );
+    }
+
+    #[test]
+    fn ceiling_overlap_is_nudged_to_nearest_free_area() {
+        let platforms = [platform(0.0, 0.0, 1024.0, 40.0)];
+
+        assert_eq!(
+            resolve(&area(100.0, 20.0, 130.0, 200.0), &platforms, &BOUNDS),
+            Some((100.0, 44.0, TeleportResult::Nudged))
+        );
+    }
+
+    #[test]
+    fn ties_prefer_moving_up() {
+        let platforms = [platform(0.0, 296.0, 1024.0, 8.0)];
+
+        assert_eq!(
+            resolve(&area(100.0, 200.0, 130.0, 200.0), &platforms, &BOUNDS),
+            Some((100.0, 96.0, TeleportResult::Nudged))
+        );
+    }
+
+    #[test]
+    fn enclosed_spot_is_blocked() {
+        let platforms = [
+            platform(0.0, 0.0, 1024.0, 200.0),
+            platform(0.0, 350.0, 1024.0, 226.0),
+        ];
+
+        assert_eq!(
+            resolve(&area(100.0, 250.0, 130.0, 200.0), &platforms, &BOUNDS),
+            None
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_candidates_are_rejected() {
+        assert_eq!(
+            resolve(&area(-5.0, 200.0, 130.0, 200.0), &[], &BOUNDS),
+            Some((3.0, 200.0, TeleportResult::Nudged))
+        );
+
+        let narrow = area(0.0, 0.0, 100.0, 576.0);
+
+        assert_eq!(resolve(&area(0.0, 200.0, 130.0, 200.0), &[], &narrow), None);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use crate::{
+    level::{Area, TilemapDescription},
+    render::Rect,
+};
+
+const NORTH: u8 = 1;
+const EAST: u8 = 2;
+const SOUTH: u8 = 4;
+const WEST: u8 = 8;
+
+const INNER_NORTH_EAST: u8 = 16;
+const INNER_SOUTH_EAST: u8 = 17;
+const INNER_SOUTH_WEST: u8 = 18;
+const INNER_NORTH_WEST: u8 = 19;
+
+pub struct Tilemap {
+    tileset: String,
+    tile_size: f64,
+    x: f64,
+    y: f64,
+    color: String,
+    tiles: Vec<Vec<u8>>,
+    sprites: Vec<(Rect, Rect)>,
+}
+
+impl Tilemap {
+    pub fn new(description: &TilemapDescription) -> Self {
+        let tiles = description
+            .rows
+            .iter()
+            .map(|row| {
+                row.chars()
+                    .map(|tile| tile.to_digit(10).map_or(0, |id| id as u8))
+                    .collect()
+            })
+            .collect();
+
+        let mut tilemap = Self {
+            tileset: description.tileset.clone(),
+            tile_size: description.tile_size,
+            x: description.x,
+            y: description.y,
+            color: description.color.clone(),
+            tiles,
+            sprites: Vec::new(),
+        };
+
+        tilemap.sprites = tilemap.autotile();
+        tilemap
+    }
+
+    pub fn tileset(&self) -> &str {
+        &self.tileset
+    }
+
+    pub fn color(&self) -> &str {
+        &self.color
+    }
+
+    pub fn sprites(&self) -> &[(Rect, Rect)] {
+        &self.sprites
+    }
+
+    fn tile(&self, column: isize, row: isize) -> Option<u8> {
+        if row < 0 || column < 0 {
+            return None;
+        }
+
+        self.tiles
+            .get(row as usize)
+            .and_then(|tiles| tiles.get(column as usize))
+            .copied()
+    }
+
+    fn connected(&self, id: u8, column: isize, row: isize) -> bool {
+        self.tile(column, row).is_none_or(|other| other == id)
+    }
+
+    fn autotile(&self) -> Vec<(Rect, Rect)> {
+        let mut sprites = Vec::new();
+
+        for (row, tiles) in self.tiles.iter().enumerate() {
+            for (column, &id) in tiles.iter().enumerate() {
+                if id == 0 {
+                    continue;
+                }
+
+                let (column, row) = (column as isize, row as isize);
+                let connected = |dx: isize, dy: isize| self.connected(id, column + dx, row + dy);
+
+                let north = connected(0, -1);
+                let east = connected(1, 0);
+                let south = connected(0, 1);
+                let west = connected(-1, 0);
+
+                let mask = [(north, NORTH), (east, EAST), (south, SOUTH), (west, WEST)]
+                    .into_iter()
+                    .filter(|(connected, _)| *connected)
+                    .fold(0, |mask, (_, bit)| mask | bit);
+
+                let destination = Rect::new(
+                    self.x + column as f64 * self.tile_size,
+                    self.y + row as f64 * self.tile_size,
+                    self.tile_size,
+                    self.tile_size,
+                );
+
+                sprites.push((self.source(id, mask), destination));
+
+                let corners = [
+                    (north && east && !connected(1, -1), INNER_NORTH_EAST),
+                    (south && east && !connected(1, 1), INNER_SOUTH_EAST),
+                    (south && west && !connected(-1, 1), INNER_SOUTH_WEST),
+                    (north && west && !connected(-1, -1), INNER_NORTH_WEST),
+                ];
+
+                for (_, corner) in corners.into_iter().filter(|(inner, _)| *inner) {
+                    sprites.push((self.source(id, corner), destination));
+                }
+            }
+        }
+
+        sprites
+    }
+
+    fn source(&self, id: u8, index: u8) -> Rect {
+        Rect::new(
+            index as f64 * self.tile_size,
+            (id - 1) as f64 * self.tile_size,
+            self.tile_size,
+            self.tile_size,
+        )
+    }
+
+    pub fn collision_areas(&self) -> Vec<Area> {
+        let mut areas: Vec<Area> = Vec::new();
+        let mut open: HashMap<(usize, usize), usize> = HashMap::new();
+
+        for (row, tiles) in self.tiles.iter().enumerate() {
+            let mut next = HashMap::new();
+            let mut column = 0;
+
+            while column < tiles.len() {
+                if tiles[column] == 0 {
+                    column += 1;
+                    continue;
+                }
+
+                let start = column;
+
+                while column < tiles.len() && tiles[column] != 0 {
+                    column += 1;
+                }
+
+                let index = match open.remove(&(start, column)) {
+                    Some(index) => {
+                        areas[index].height += self.tile_size;
+                        index
+                    }
+                    None => {
+                        areas.push(Area {
+                            x: self.x + start as f64 * self.tile_size,
+                            y: self.y + row as f64 * self.tile_size,
+                            width: (column - start) as f64 * self.tile_size,
+                            height: self.tile_size,
+                        });
+                        areas.len() - 1
+                    }
+                };
+
+                next.insert((start, column), index);
+            }
+
+            open = next;
+        }
+
+        areas
+    }
+}
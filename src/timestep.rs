@@ -0,0 +1,100 @@
+use crate::constants::time::TIME;
+
+pub struct FixedTimestep {
+    accumulator: f64,
+    last_time: Option<f64>,
+}
+
+impl FixedTimestep {
+    pub fn new() -> Self {
+        Self {
+            accumulator: 0.0,
+            last_time: None,
+        }
+    }
+
+    pub fn advance(&mut self, now: f64) -> u32 {
+        let frame_time = match self.last_time {
+            Some(last_time) => ((now - last_time) / 1000.0).clamp(0.0, TIME.max_frame_time()),
+            None => 0.0,
+        };
+        self.last_time = Some(now);
+        self.accumulator += frame_time;
+
+        let mut ticks = 0;
+
+        while self.accumulator >= TIME.dt() {
+            self.accumulator -= TIME.dt();
+            ticks += 1;
+        }
+
+        ticks
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.accumulator / TIME.dt()
+    }
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticks_over_one_second(rate: u32) -> u32 {
+        let mut timestep = FixedTimestep::new();
+
+        (0..=rate)
+            .map(|frame| timestep.advance(frame as f64 * 1000.0 / rate as f64))
+            .sum()
+    }
+
+    #[test]
+    fn frame_rate_does_not_change_tick_count() {
+        let ticks = TIME.tick_rate() as u32;
+
+        assert!(ticks_over_one_second(60).abs_diff(ticks) <= 1);
+        assert!(ticks_over_one_second(144).abs_diff(ticks) <= 1);
+        assert!(ticks_over_one_second(60).abs_diff(ticks_over_one_second(144)) <= 1);
+    }
+
+    #[test]
+    fn first_frame_does_not_tick() {
+        let mut timestep = FixedTimestep::new();
+
+        assert_eq!(timestep.advance(12_345.0), 0);
+        assert_eq!(timestep.alpha(), 0.0);
+    }
+
+    #[test]
+    fn large_gaps_are_clamped() {
+        let mut timestep = FixedTimestep::new();
+
+        timestep.advance(0.0);
+        let ticks = timestep.advance(5_000.0);
+
+        assert_eq!(
+            ticks,
+            (TIME.max_frame_time() * TIME.tick_rate()).round() as u32
+        );
+        assert_eq!(timestep.advance(4_000.0), 0);
+    }
+
+    #[test]
+    fn alpha_stays_in_unit_range() {
+        let mut timestep = FixedTimestep::new();
+        let mut now = 0.0;
+
+        for frame in 0..1000 {
+            now += [3.0, 7.5, 16.7, 33.3, 1.1][frame % 5];
+            timestep.advance(now);
+
+            assert!((0.0..1.0).contains(&timestep.alpha()));
+        }
+    }
+}
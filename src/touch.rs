@@ -0,0 +1,308 @@
+use std::cell::RefCell;
+
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlCanvasElement, TouchEvent};
+
+use crate::{
+    bindings::Action,
+    camera::Camera,
+    components::position::Position,
+    constants::{controls::ThrowMode, touch::TOUCH_LAYOUT},
+    events::canvas_position,
+    input::{InputEvent, InputQueue, apply_deadzone},
+    render::{Rect, Renderer, draw_reticle},
+    sprites::{Sprite, weapon::WeaponState},
+    world::World,
+};
+
+const JOYSTICK_DEADZONE: f64 = 0.15;
+
+#[derive(Clone, Copy, PartialEq)]
+enum TouchRole {
+    Joystick,
+    Jump,
+    Aim,
+}
+
+#[derive(Clone, Copy)]
+struct TouchPoint {
+    id: i32,
+    role: TouchRole,
+    start_x: f64,
+    start_y: f64,
+    x: f64,
+    y: f64,
+    charging: bool,
+}
+
+#[derive(Default)]
+pub struct Touches {
+    enabled: bool,
+    active: Vec<TouchPoint>,
+    released: Vec<TouchPoint>,
+    move_x: f64,
+}
+
+impl Touches {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn move_x(&self) -> f64 {
+        self.move_x
+    }
+}
+
+fn changed_touches(canvas: &HtmlCanvasElement, event: &Event) -> Vec<(i32, Position)> {
+    let event = event.dyn_ref::<TouchEvent>().unwrap();
+    event.prevent_default();
+
+    let touches = event.changed_touches();
+
+    (0..touches.length())
+        .filter_map(|index| touches.get(index))
+        .map(|touch| {
+            (
+                touch.identifier(),
+                canvas_position(canvas, touch.client_x() as f64, touch.client_y() as f64),
+            )
+        })
+        .collect()
+}
+
+pub fn touch_start(
+    touches: &RefCell<Touches>,
+    queue: &RefCell<InputQueue>,
+    canvas: &HtmlCanvasElement,
+    event: &Event,
+) {
+    let changed = changed_touches(canvas, event);
+    let mut touches = touches.borrow_mut();
+
+    touches.enabled = true;
+
+    for (id, position) in changed {
+        let x = position.x();
+        let y = position.y();
+
+        let has_joystick = touches
+            .active
+            .iter()
+            .any(|point| point.role == TouchRole::Joystick);
+
+        let role = if TOUCH_LAYOUT.in_jump_button(x, y) {
+            TouchRole::Jump
+        } else if x < TOUCH_LAYOUT.joystick_zone_width() && !has_joystick {
+            TouchRole::Joystick
+        } else {
+            TouchRole::Aim
+        };
+
+        if role == TouchRole::Jump {
+            queue.borrow_mut().push(InputEvent::Pressed(Action::Jump));
+        }
+
+        touches.active.push(TouchPoint {
+            id,
+            role,
+            start_x: x,
+            start_y: y,
+            x,
+            y,
+            charging: false,
+        });
+    }
+}
+
+pub fn touch_move(touches: &RefCell<Touches>, canvas: &HtmlCanvasElement, event: &Event) {
+    let changed = changed_touches(canvas, event);
+    let mut touches = touches.borrow_mut();
+
+    for (id, position) in changed {
+        if let Some(point) = touches.active.iter_mut().find(|point| point.id == id) {
+            point.x = position.x();
+            point.y = position.y();
+        }
+    }
+}
+
+fn remove_touches(
+    touches: &RefCell<Touches>,
+    queue: &RefCell<InputQueue>,
+    canvas: &HtmlCanvasElement,
+    event: &Event,
+    release: bool,
+) {
+    let changed = changed_touches(canvas, event);
+    let mut touches = touches.borrow_mut();
+
+    for (id, position) in changed {
+        let Some(index) = touches.active.iter().position(|point| point.id == id) else {
+            continue;
+        };
+
+        let mut point = touches.active.remove(index);
+        point.x = position.x();
+        point.y = position.y();
+
+        match point.role {
+            TouchRole::Jump => queue.borrow_mut().push(InputEvent::Released(Action::Jump)),
+            TouchRole::Aim if release => touches.released.push(point),
+            TouchRole::Aim if point.charging => {
+                queue.borrow_mut().push(InputEvent::Released(Action::Throw))
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn touch_end(
+    touches: &RefCell<Touches>,
+    queue: &RefCell<InputQueue>,
+    canvas: &HtmlCanvasElement,
+    event: &Event,
+) {
+    remove_touches(touches, queue, canvas, event, true);
+}
+
+pub fn touch_cancel(
+    touches: &RefCell<Touches>,
+    queue: &RefCell<InputQueue>,
+    canvas: &HtmlCanvasElement,
+    event: &Event,
+) {
+    remove_touches(touches, queue, canvas, event, false);
+}
+
+pub fn poll_touch(
+    touches: &RefCell<Touches>,
+    queue: &RefCell<InputQueue>,
+    world: &World,
+    camera: &Camera,
+) {
+    let mut touches = touches.borrow_mut();
+    let mut queue = queue.borrow_mut();
+
+    let joystick = touches
+        .active
+        .iter()
+        .find(|point| point.role == TouchRole::Joystick);
+
+    touches.move_x = joystick.map_or(0.0, |point| {
+        let radius = TOUCH_LAYOUT.joystick_radius();
+        let (move_x, _) = apply_deadzone(
+            ((point.x - point.start_x) / radius).clamp(-1.0, 1.0),
+            ((point.y - point.start_y) / radius).clamp(-1.0, 1.0),
+            JOYSTICK_DEADZONE,
+        );
+        move_x
+    });
+
+    let weapon = world.weapon();
+    let margin = TOUCH_LAYOUT.tap_margin();
+    let charge = world.throw_mode() == ThrowMode::Charge && weapon.state() == &WeaponState::Carried;
+
+    for point in touches
+        .active
+        .iter_mut()
+        .filter(|point| point.role == TouchRole::Aim)
+    {
+        if !point.charging && !charge {
+            continue;
+        }
+
+        queue.push(InputEvent::Cursor(Position::new(point.x, point.y)));
+
+        if !point.charging {
+            point.charging = true;
+            queue.push(InputEvent::Pressed(Action::Throw));
+        }
+    }
+
+    for point in std::mem::take(&mut touches.released) {
+        if point.charging {
+            queue.push(InputEvent::Cursor(Position::new(point.x, point.y)));
+            queue.push(InputEvent::Released(Action::Throw));
+            continue;
+        }
+
+        let tap =
+            (point.x - point.start_x).hypot(point.y - point.start_y) <= TOUCH_LAYOUT.tap_distance();
+
+        let target = camera.to_world(&Position::new(point.x, point.y));
+
+        let on_weapon = target.x() >= weapon.position().x() - margin
+            && target.x() <= weapon.position().x() + weapon.width() + margin
+            && target.y() >= weapon.position().y() - margin
+            && target.y() <= weapon.position().y() + weapon.height() + margin;
+
+        let throw = match weapon.state() {
+            WeaponState::Carried => true,
+            WeaponState::Stuck(_) => tap && on_weapon,
+            WeaponState::Thrown | WeaponState::Returning => false,
+        };
+
+        if throw {
+            queue.push(InputEvent::Cursor(Position::new(point.x, point.y)));
+            queue.push(InputEvent::Pressed(Action::Throw));
+            queue.push(InputEvent::Released(Action::Throw));
+        }
+    }
+}
+
+pub fn draw_touch_controls(renderer: &mut dyn Renderer, touches: &Touches) {
+    if !touches.enabled {
+        return;
+    }
+
+    let jump = touches
+        .active
+        .iter()
+        .any(|point| point.role == TouchRole::Jump);
+
+    renderer.fill_rect(
+        Rect::new(
+            TOUCH_LAYOUT.jump_x(),
+            TOUCH_LAYOUT.jump_y(),
+            TOUCH_LAYOUT.jump_size(),
+            TOUCH_LAYOUT.jump_size(),
+        ),
+        if jump {
+            "rgba(0, 0, 0, 0.5)"
+        } else {
+            "rgba(0, 0, 0, 0.25)"
+        },
+    );
+
+    for point in &touches.active {
+        match point.role {
+            TouchRole::Joystick => {
+                let radius = TOUCH_LAYOUT.joystick_radius();
+                let knob = radius / 2.0;
+                let dx = (point.x - point.start_x).clamp(-radius, radius);
+                let dy = (point.y - point.start_y).clamp(-radius, radius);
+
+                renderer.fill_rect(
+                    Rect::new(
+                        point.start_x - radius,
+                        point.start_y - radius,
+                        radius * 2.0,
+                        radius * 2.0,
+                    ),
+                    "rgba(0, 0, 0, 0.25)",
+                );
+                renderer.fill_rect(
+                    Rect::new(
+                        point.start_x + dx - knob / 2.0,
+                        point.start_y + dy - knob / 2.0,
+                        knob,
+                        knob,
+                    ),
+                    "rgba(0, 0, 0, 0.5)",
+                );
+            }
+            TouchRole::Aim => draw_reticle(renderer, &Position::new(point.x, point.y)),
+            TouchRole::Jump => {}
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use wasm_bindgen::JsValue;
+use web_sys::{HtmlCanvasElement, Window};
+
+use crate::{constants::canvas::CANVAS, render::canvas::CanvasRenderer};
+
+pub fn fit_canvas(
+    window: &Window,
+    canvas: &HtmlCanvasElement,
+    renderer: &mut CanvasRenderer,
+) -> Result<(), JsValue> {
+    let width = window.inner_width()?.as_f64().unwrap_or(CANVAS.width());
+    let height = window.inner_height()?.as_f64().unwrap_or(CANVAS.height());
+    let ratio = window.device_pixel_ratio().max(1.0);
+
+    let scale = (width / CANVAS.width()).min(height / CANVAS.height());
+    let css_width = (CANVAS.width() * scale).floor();
+    let css_height = (CANVAS.height() * scale).floor();
+
+    let style = canvas.style();
+    style.set_property("width", &format!("{css_width}px"))?;
+    style.set_property("height", &format!("{css_height}px"))?;
+
+    canvas.set_width((css_width * ratio).round() as u32);
+    canvas.set_height((css_height * ratio).round() as u32);
+
+    renderer.set_scale(
+        canvas.width() as f64 / CANVAS.width(),
+        canvas.height() as f64 / CANVAS.height(),
+    );
+
+    Ok(())
+}
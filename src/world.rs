@@ -0,0 +1,382 @@
+use crate::{
+    components::{
+        collision::{intersects, sweep},
+        grid::SpatialGrid,
+        hit_box::HitBox,
+        position::Position,
+        rng::Rng,
+    },
+    constants::{
+        canvas::CANVAS, controls::ThrowMode, player::PLAYER, teleport::TELEPORT, time::TIME,
+        weapon::WEAPON,
+    },
+    input::InputFrame,
+    level::{Area, Level, Material, Momentum},
+    sprites::{
+        Sprite,
+        goal::Goal,
+        platform::Platform,
+        player::Player,
+        weapon::{StuckOn, Weapon, WeaponState},
+    },
+    teleport::{TeleportResult, Terrain},
+    tilemap::Tilemap,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LevelStats {
+    ticks: u32,
+    throws: u32,
+    teleports: u32,
+}
+
+impl LevelStats {
+    pub fn ticks(&self) -> u32 {
+        self.ticks
+    }
+
+    pub fn time(&self) -> f64 {
+        self.ticks as f64 * TIME.dt()
+    }
+
+    pub fn throws(&self) -> u32 {
+        self.throws
+    }
+
+    pub fn teleports(&self) -> u32 {
+        self.teleports
+    }
+}
+
+const GRID_CELL_SIZE: f64 = 128.0;
+
+pub struct World {
+    name: String,
+    player: Player,
+    weapon: Weapon,
+    platforms: Vec<Platform>,
+    grid: SpatialGrid,
+    goal: Option<Goal>,
+    gravity: f64,
+    bounds: Area,
+    background: String,
+    stats: LevelStats,
+    completed: bool,
+    throw_mode: ThrowMode,
+    seed: u64,
+    rng: Rng,
+    tilemap: Option<Tilemap>,
+    tile_platforms: usize,
+    teleport: Option<(TeleportResult, u32)>,
+    air_teleport: Option<Momentum>,
+}
+
+impl World {
+    pub fn new(player: Player, weapon: Weapon, platforms: Vec<Platform>) -> Self {
+        Self {
+            name: String::new(),
+            player,
+            weapon,
+            grid: SpatialGrid::new(GRID_CELL_SIZE, &platforms),
+            platforms,
+            goal: None,
+            gravity: CANVAS.gravity(),
+            bounds: Area {
+                x: 0.0,
+                y: 0.0,
+                width: CANVAS.width(),
+                height: CANVAS.height(),
+            },
+            background: "lightgrey".to_string(),
+            stats: LevelStats::default(),
+            completed: false,
+            throw_mode: ThrowMode::default(),
+            seed: 0,
+            rng: Rng::new(0),
+            tilemap: None,
+            tile_platforms: 0,
+            teleport: None,
+            air_teleport: None,
+        }
+    }
+
+    pub fn from_level(level: &Level) -> Self {
+        Self::from_level_seeded(level, 0)
+    }
+
+    pub fn from_level_seeded(level: &Level, seed: u64) -> Self {
+        let player_area = level.player_area();
+
+        let player = Player::new(
+            player_area.x,
+            player_area.y,
+            PLAYER.sprite().width(),
+            PLAYER.sprite().height(),
+            Some(HitBox::new(player_area.width, player_area.height)),
+            "blue".to_string(),
+        );
+
+        let mut weapon = match level.weapon_spawn {
+            Some(spawn) => Weapon::new(
+                spawn.x,
+                spawn.y,
+                WEAPON.sprite().width(),
+                WEAPON.sprite().height(),
+                None,
+                "red".to_string(),
+            ),
+            None => Weapon::new(
+                player_area.x + WEAPON.sprite().x_offset(),
+                player_area.y + WEAPON.sprite().y_offset(),
+                WEAPON.sprite().width(),
+                WEAPON.sprite().height(),
+                None,
+                "red".to_string(),
+            ),
+        };
+
+        if level.weapon_spawn.is_some() {
+            weapon.set_state(WeaponState::Stuck(StuckOn::Bottom));
+        }
+
+        let mut platforms: Vec<Platform> = level
+            .platforms
+            .iter()
+            .map(|platform| {
+                Platform::new(
+                    platform.x,
+                    platform.y,
+                    platform.width,
+                    platform.height,
+                    None,
+                    platform.color.clone(),
+                    platform.material,
+                )
+            })
+            .collect();
+
+        let tilemap = level.tilemap.as_ref().map(Tilemap::new);
+        let level_platforms = platforms.len();
+
+        if let Some(tilemap) = &tilemap {
+            platforms.extend(tilemap.collision_areas().into_iter().map(|area| {
+                Platform::new(
+                    area.x,
+                    area.y,
+                    area.width,
+                    area.height,
+                    None,
+                    tilemap.color().to_string(),
+                    Material::Sticky,
+                )
+            }));
+        }
+
+        let tile_platforms = platforms.len() - level_platforms;
+
+        let goal = level
+            .goal
+            .map(|goal| Goal::new(goal.x, goal.y, goal.width, goal.height, "green".to_string()));
+
+        Self {
+            name: level.name.clone(),
+            player,
+            weapon,
+            grid: SpatialGrid::new(GRID_CELL_SIZE, &platforms),
+            platforms,
+            goal,
+            gravity: level.gravity.unwrap_or(CANVAS.gravity()),
+            bounds: level.bounds(),
+            background: level.background.clone(),
+            stats: LevelStats::default(),
+            completed: false,
+            throw_mode: ThrowMode::default(),
+            seed,
+            rng: Rng::new(seed),
+            tile_platforms,
+            tilemap,
+            teleport: None,
+            air_teleport: level.air_teleport,
+        }
+    }
+
+    pub fn step(&mut self, input: &InputFrame) {
+        if self.completed {
+            return;
+        }
+
+        self.stats.ticks += 1;
+
+        self.player.apply_physics(self.gravity, &self.bounds);
+        self.player.resolve_collisions(&self.platforms, &self.grid);
+        self.player.apply_keys(input);
+
+        let was_carried = matches!(self.weapon.state(), WeaponState::Carried);
+        let teleport = self.player.apply_clicks(
+            input,
+            &mut self.weapon,
+            self.throw_mode,
+            self.air_teleport,
+            &Terrain {
+                platforms: &self.platforms,
+                grid: &self.grid,
+                bounds: &self.bounds,
+            },
+        );
+        let is_carried = matches!(self.weapon.state(), WeaponState::Carried);
+
+        if let Some(result) = teleport {
+            self.teleport = Some((result, self.stats.ticks));
+        }
+
+        if was_carried && !is_carried {
+            self.stats.throws += 1;
+        } else if !was_carried && is_carried {
+            self.stats.teleports += 1;
+        }
+
+        match self.weapon.state() {
+            WeaponState::Carried => self.weapon.follow_player(&self.player),
+            WeaponState::Thrown => {
+                self.weapon.apply_physics(self.gravity, &self.bounds);
+                self.shatter_glass();
+                self.weapon.resolve_collisions(&self.platforms, &self.grid);
+            }
+            WeaponState::Returning => {
+                self.weapon.home(&self.player);
+
+                if WEAPON.recall().collides() {
+                    self.shatter_glass();
+                    self.weapon.resolve_collisions(&self.platforms, &self.grid);
+                }
+
+                if matches!(self.weapon.state(), WeaponState::Carried) {
+                    self.player.catch_weapon();
+                }
+            }
+            WeaponState::Stuck(_) => {}
+        }
+
+        self.player.animate();
+        self.weapon.animate();
+
+        if let Some(goal) = &self.goal
+            && intersects(&self.player, goal)
+        {
+            self.completed = true;
+        }
+    }
+
+    fn shatter_glass(&mut self) {
+        let shattered: Vec<usize> = self
+            .grid
+            .query_swept(&self.weapon)
+            .into_iter()
+            .filter(|index| {
+                let platform = &self.platforms[*index];
+
+                platform.material() == Material::Glass
+                    && (sweep(&self.weapon, platform).is_some()
+                        || intersects(&self.weapon, platform))
+            })
+            .collect();
+
+        if shattered.is_empty() {
+            return;
+        }
+
+        for index in shattered.into_iter().rev() {
+            self.platforms.remove(index);
+        }
+
+        self.grid = SpatialGrid::new(GRID_CELL_SIZE, &self.platforms);
+        self.weapon.shatter();
+    }
+
+    pub fn throw_mode(&self) -> ThrowMode {
+        self.throw_mode
+    }
+
+    pub fn set_throw_mode(&mut self, throw_mode: ThrowMode) {
+        self.throw_mode = throw_mode;
+    }
+
+    pub fn throw_power(&self, target: &Position) -> f64 {
+        match self.throw_mode {
+            ThrowMode::Charge => WEAPON
+                .throw()
+                .charged_power(self.player.charge().unwrap_or(0.0)),
+            ThrowMode::Distance => self.weapon.distance_power(target.x(), target.y()),
+        }
+    }
+
+    pub fn teleport(&self) -> Option<TeleportResult> {
+        let (result, tick) = self.teleport?;
+
+        ((self.stats.ticks - tick) as f64 * TIME.dt() < TELEPORT.feedback_time()).then_some(result)
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+
+    pub fn weapon(&self) -> &Weapon {
+        &self.weapon
+    }
+
+    pub fn platforms(&self) -> &[Platform] {
+        &self.platforms
+    }
+
+    pub fn level_platforms(&self) -> &[Platform] {
+        &self.platforms[..self.platforms.len() - self.tile_platforms]
+    }
+
+    pub fn tile_platforms(&self) -> &[Platform] {
+        &self.platforms[self.platforms.len() - self.tile_platforms..]
+    }
+
+    pub fn tilemap(&self) -> Option<&Tilemap> {
+        self.tilemap.as_ref()
+    }
+
+    pub fn grid(&self) -> &SpatialGrid {
+        &self.grid
+    }
+
+    pub fn goal(&self) -> Option<&Goal> {
+        self.goal.as_ref()
+    }
+
+    pub fn gravity(&self) -> f64 {
+        self.gravity
+    }
+
+    pub fn bounds(&self) -> &Area {
+        &self.bounds
+    }
+
+    pub fn background(&self) -> &str {
+        &self.background
+    }
+
+    pub fn stats(&self) -> &LevelStats {
+        &self.stats
+    }
+
+    pub fn completed(&self) -> bool {
+        self.completed
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    pub fn rng(&mut self) -> &mut Rng {
+        &mut self.rng
+    }
+}
@@ -0,0 +1,203 @@
+use crimson_recall::{
+    level::Level,
+    render::{
+        Rect, draw_world,
+        recording::{DrawCommand, RecordingRenderer},
+    },
+    world::World,
+};
+
+#[test]
+fn draw_world_snapshot() {
+    let level = Level::from_ron(
+        "(
+            name: \"Snapshot\",
+            background: \"white\",
+            player_spawn: (x: 40.0, y: 376.0),
+            goal: Some((x: 920.0, y: 456.0, width: 80.0, height: 120.0)),
+            platforms: [(x: 500.0, y: 400.0, width: 200.0, height: 40.0, color: \"orange\")],
+        )",
+    )
+    .unwrap();
+    let world = World::from_level(&level);
+    let mut renderer = RecordingRenderer::new();
+
+    draw_world(&mut renderer, &world, 1.0);
+
+    let fill = |x, y, width, height, color: &str| DrawCommand::FillRect {
+        rect: Rect::new(x, y, width, height),
+        color: color.to_string(),
+    };
+    let image = |image: &str, source, destination| DrawCommand::DrawImage {
+        image: image.to_string(),
+        source,
+        destination,
+    };
+
+    assert_eq!(
+        renderer.commands(),
+        [
+            fill(0.0, 0.0, 1024.0, 576.0, "white"),
+            fill(500.0, 400.0, 200.0, 40.0, "orange"),
+            fill(920.0, 456.0, 80.0, 120.0, "green"),
+            image(
+                "assets/player.png",
+                Rect::new(0.0, 0.0, 50.0, 100.0),
+                Rect::new(40.0, 376.0, 100.0, 200.0),
+            ),
+            DrawCommand::PushTransform {
+                x: 155.0,
+                y: 466.0,
+                angle: 0.0,
+            },
+            image(
+                "assets/weapon.png",
+                Rect::new(0.0, 0.0, 15.0, 50.0),
+                Rect::new(-15.0, -50.0, 30.0, 100.0),
+            ),
+            DrawCommand::PopTransform,
+        ]
+    );
+}
+
+#[test]
+fn tilemap_platforms_are_a_loading_fallback() {
+    let level = Level::from_ron(
+        "(
+            name: \"Tiles\",
+            player_spawn: (x: 40.0, y: 376.0),
+            platforms: [(x: 500.0, y: 400.0, width: 200.0, height: 40.0, color: \"orange\")],
+            tilemap: Some((
+                tileset: \"assets/tiles.png\",
+                tile_size: 32.0,
+                y: 512.0,
+                color: \"brown\",
+                rows: [\"\", \"00000011\"],
+            )),
+        )",
+    )
+    .unwrap();
+    let world = World::from_level(&level);
+    let mut renderer = RecordingRenderer::new();
+
+    let tiles = |commands: &[DrawCommand]| {
+        commands
+            .iter()
+            .filter(|command| match command {
+                DrawCommand::FillRect { color, .. } => color == "brown",
+                DrawCommand::DrawImage { image, .. } => image == "assets/tiles.png",
+                _ => false,
+            })
+            .cloned()
+            .collect::<Vec<_>>()
+    };
+
+    draw_world(&mut renderer, &world, 1.0);
+    let ready = renderer.take_commands();
+
+    assert_eq!(tiles(&ready).len(), 2);
+    assert!(
+        tiles(&ready)
+            .iter()
+            .all(|command| matches!(command, DrawCommand::DrawImage { .. }))
+    );
+    assert!(ready.contains(&DrawCommand::FillRect {
+        rect: Rect::new(500.0, 400.0, 200.0, 40.0),
+        color: "orange".to_string(),
+    }));
+
+    renderer.set_pending_images(true);
+    draw_world(&mut renderer, &world, 1.0);
+
+    assert_eq!(
+        tiles(renderer.commands()),
+        [DrawCommand::FillRect {
+            rect: Rect::new(192.0, 544.0, 64.0, 32.0),
+            color: "brown".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn summary_names_the_restart_bindings() {
+    use crimson_recall::{
+        bindings::{Action, Binding, Bindings},
+        camera::Camera,
+        campaign::Campaign,
+        input::{InputEvent, InputQueue},
+        render::draw_campaign,
+    };
+
+    let level = Level::from_ron(
+        "(name: \"Short walk\", player_spawn: (x: 40.0, y: 376.0), \
+         goal: Some((x: 300.0, y: 456.0, width: 80.0, height: 120.0)))",
+    )
+    .unwrap();
+    let mut campaign = Campaign::new(vec![level], 0).unwrap();
+    let mut queue = InputQueue::new();
+
+    queue.push(InputEvent::Pressed(Action::MoveRight));
+
+    while campaign.summary().is_none() {
+        campaign.step(&queue.drain());
+    }
+
+    let mut renderer = RecordingRenderer::new();
+    draw_campaign(&mut renderer, &campaign, &Camera::new(1024.0, 576.0), 1.0);
+
+    assert!(renderer.commands().iter().any(|command| matches!(
+        command,
+        DrawCommand::DrawText { text, .. } if text == "Press R or Gamepad9 to retry"
+    )));
+
+    let mut bindings = Bindings::default();
+    bindings.rebind(Action::Restart, Binding::Key("Backspace".to_string()));
+    assert_eq!(bindings.hint(Action::Restart).as_deref(), Some("Backspace"));
+
+    bindings.unbind(&Binding::Key("Backspace".to_string()));
+    assert_eq!(bindings.hint(Action::Restart), None);
+}
+
+#[test]
+fn aim_preview_stops_at_platforms() {
+    use crimson_recall::{
+        camera::Camera, components::position::Position, constants::controls::ThrowMode,
+        render::draw_aim_preview,
+    };
+
+    let dots = |platforms: &str| {
+        let level = Level::from_ron(&format!(
+            "(name: \"Aim\", width: Some(3000.0), player_spawn: (x: 40.0, y: 376.0), \
+             platforms: [{platforms}])"
+        ))
+        .unwrap();
+        let mut world = World::from_level(&level);
+        world.set_throw_mode(ThrowMode::Distance);
+        let mut renderer = RecordingRenderer::new();
+
+        draw_aim_preview(
+            &mut renderer,
+            &world,
+            &Position::new(900.0, 300.0),
+            &Camera::new(1024.0, 576.0),
+            1.0,
+        );
+
+        renderer
+            .commands()
+            .iter()
+            .filter_map(|command| match command {
+                DrawCommand::FillRect { rect, .. } => Some(rect.x()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let open = dots("");
+    let walled = dots("(x: 600.0, y: 0.0, width: 40.0, height: 576.0, color: \"gray\")");
+
+    assert!(open.iter().any(|&x| x > 640.0));
+    assert!(!walled.is_empty() && walled.len() < open.len());
+    assert!(walled.iter().all(|&x| x < 600.0));
+    assert_eq!(walled, open[..walled.len()]);
+}
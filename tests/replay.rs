@@ -0,0 +1,147 @@
+use crimson_recall::{
+    bindings::Action,
+    components::position::Position,
+    input::{InputEvent, InputFrame, InputQueue},
+    level::Level,
+    replay::Replay,
+    sprites::Sprite,
+    world::World,
+};
+
+const SEED: u64 = 42;
+
+fn level() -> Level {
+    Level::from_ron(&std::fs::read_to_string("levels/level-1.ron").unwrap()).unwrap()
+}
+
+fn inputs() -> Vec<InputFrame> {
+    let mut queue = InputQueue::new();
+
+    (0..600)
+        .map(|tick| {
+            match tick {
+                10 => queue.push(InputEvent::Pressed(Action::MoveRight)),
+                60 => queue.push(InputEvent::Released(Action::MoveRight)),
+                100 => {
+                    queue.push(InputEvent::Cursor(Position::new(900.0, 100.0)));
+                    queue.push(InputEvent::Pressed(Action::Throw));
+                }
+                220 => queue.push(InputEvent::Released(Action::Throw)),
+                450 => queue.push(InputEvent::Pressed(Action::Throw)),
+                451 => queue.push(InputEvent::Released(Action::Throw)),
+                _ => {}
+            }
+
+            queue.drain()
+        })
+        .collect()
+}
+
+fn run(seed: u64) -> (World, Replay) {
+    let level = level();
+    let mut world = World::from_level_seeded(&level, seed);
+    let mut replay = Replay::new(level.name.clone(), seed, world.throw_mode());
+
+    for input in inputs() {
+        replay.record(&input);
+        world.step(&input);
+    }
+
+    (world, replay)
+}
+
+#[test]
+fn replay_matches_live_run() {
+    let (live, replay) = run(SEED);
+
+    let parsed = Replay::from_json(&replay.to_json()).unwrap();
+    let replayed = parsed.simulate(&level()).unwrap();
+
+    assert_eq!(parsed.ticks(), 600);
+    assert_eq!(parsed.seed(), SEED);
+    assert_eq!(live.stats().throws(), 1);
+    assert_eq!(live.stats().teleports(), 1);
+
+    assert_eq!(replayed.player().position(), live.player().position());
+    assert_eq!(replayed.weapon().position(), live.weapon().position());
+    assert_eq!(replayed.weapon().state(), live.weapon().state());
+    assert_eq!(replayed.stats(), live.stats());
+}
+
+#[test]
+fn seed_does_not_change_throws() {
+    let run = |seed| {
+        let level = level();
+        let mut world = World::from_level_seeded(&level, seed);
+
+        for input in inputs().iter().take(240) {
+            world.step(input);
+        }
+
+        let next = world.rng().next_u64();
+        (world.weapon().angle().to_bits(), next)
+    };
+
+    let (angle, next) = run(SEED);
+    let (other_angle, other_next) = run(SEED + 1);
+
+    assert_eq!(run(SEED), (angle, next));
+    assert_eq!(angle, other_angle);
+    assert_ne!(next, other_next);
+}
+
+#[test]
+fn completed_final_level_is_recorded() {
+    use crimson_recall::campaign::Campaign;
+
+    let level = Level::from_ron(
+        "(name: \"Short walk\", player_spawn: (x: 40.0, y: 376.0), \
+         goal: Some((x: 300.0, y: 456.0, width: 80.0, height: 120.0)))",
+    )
+    .unwrap();
+    let mut campaign = Campaign::new(vec![level.clone()], SEED).unwrap();
+    let mut queue = InputQueue::new();
+
+    queue.push(InputEvent::Pressed(Action::MoveRight));
+
+    for _ in 0..240 {
+        campaign.step(&queue.drain());
+    }
+
+    assert!(campaign.summary().is_some_and(|summary| summary.last()));
+
+    let recording = campaign.take_finished_recording().unwrap();
+    let replayed = recording.simulate(&level).unwrap();
+
+    assert!(replayed.completed());
+    assert_eq!(replayed.stats(), campaign.world().stats());
+}
+
+#[test]
+fn restarted_run_is_recorded() {
+    use crimson_recall::campaign::Campaign;
+
+    let level = level();
+    let mut campaign = Campaign::new(vec![level.clone()], SEED).unwrap();
+    let mut queue = InputQueue::new();
+
+    queue.push(InputEvent::Pressed(Action::MoveRight));
+
+    for _ in 0..60 {
+        campaign.step(&queue.drain());
+    }
+
+    let position = *campaign.world().player().position();
+
+    queue.push(InputEvent::Pressed(Action::Restart));
+    campaign.step(&queue.drain());
+
+    let recording = campaign.take_finished_recording().unwrap();
+
+    assert_eq!(recording.ticks(), 60);
+    assert_eq!(
+        recording.simulate(&level).unwrap().player().position(),
+        &position
+    );
+    assert_eq!(campaign.recording().ticks(), 0);
+}
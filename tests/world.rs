@@ -0,0 +1,301 @@
+use crimson_recall::{
+    bindings::Action,
+    components::position::Position,
+    constants::{controls::ThrowMode, weapon::WEAPON},
+    input::{InputEvent, InputQueue},
+    level::Level,
+    sprites::{
+        Sprite,
+        player::Facing,
+        weapon::{StuckOn, WeaponState},
+    },
+    teleport::TeleportResult,
+    world::World,
+};
+
+const WALL: &str = "(
+    name: \"Wall\",
+    player_spawn: (x: 40.0, y: 376.0),
+    platforms: [(x: 600.0, y: 0.0, width: 40.0, height: 576.0, color: \"gray\")],
+)";
+
+fn throw_at(world: &mut World, queue: &mut InputQueue, target: Position) {
+    queue.push(InputEvent::Cursor(target));
+    queue.push(InputEvent::Pressed(Action::Throw));
+    queue.push(InputEvent::Released(Action::Throw));
+    world.step(&queue.drain());
+}
+
+#[test]
+fn throw_sticks_and_teleports() {
+    let level = Level::from_ron(WALL).unwrap();
+    let mut world = World::from_level(&level);
+    world.set_throw_mode(ThrowMode::Distance);
+    let mut queue = InputQueue::new();
+
+    world.step(&queue.drain());
+    let start = *world.player().position();
+
+    throw_at(&mut world, &mut queue, Position::new(900.0, 300.0));
+    assert_eq!(world.weapon().state(), &WeaponState::Thrown);
+    assert_eq!(world.stats().throws(), 1);
+
+    let mut ticks = 0;
+    while !matches!(world.weapon().state(), WeaponState::Stuck(_)) {
+        world.step(&queue.drain());
+        ticks += 1;
+        assert!(ticks < 600, "weapon never stuck");
+    }
+
+    assert_eq!(world.weapon().state(), &WeaponState::Stuck(StuckOn::Right));
+    assert_eq!(world.player().position(), &start);
+    assert!(world.weapon().position().x() < 600.0);
+
+    let handle = world.weapon().handle(&StuckOn::Right);
+    let weapon_width = world.weapon().width();
+
+    queue.push(InputEvent::Pressed(Action::Throw));
+    world.step(&queue.drain());
+
+    let player = world.player();
+    assert_eq!(world.weapon().state(), &WeaponState::Carried);
+    assert_eq!(world.teleport(), Some(TeleportResult::Ok));
+    assert_eq!(world.stats().teleports(), 1);
+    assert_eq!(player.facing(), Facing::Left);
+    assert_eq!(
+        player.previous_position(),
+        &Position::new(
+            handle.x() - weapon_width / 2.0 - player.width(),
+            handle.y() - player.height(),
+        )
+    );
+    assert!(player.position().x() + player.width() < 600.0);
+}
+
+#[test]
+fn landing_snaps_to_floor_and_allows_the_next_jump() {
+    let level = Level::from_ron(&std::fs::read_to_string("levels/level-1.ron").unwrap()).unwrap();
+    let mut world = World::from_level(&level);
+    let mut queue = InputQueue::new();
+
+    world.step(&queue.drain());
+    world.step(&queue.drain());
+
+    let floor = world.bounds().y + world.bounds().height - world.player().height();
+    let mut previous = world.player().position().y();
+    let mut ticks = 0;
+
+    loop {
+        world.step(&queue.drain());
+        let y = world.player().position().y();
+
+        if y <= previous {
+            break;
+        }
+
+        previous = y;
+        ticks += 1;
+        assert!(ticks < 600, "player never landed");
+    }
+
+    assert_eq!(world.player().position().y(), floor);
+
+    queue.push(InputEvent::Pressed(Action::Jump));
+    queue.push(InputEvent::Released(Action::Jump));
+    world.step(&queue.drain());
+    world.step(&queue.drain());
+
+    assert!(world.player().position().y() < floor);
+}
+
+fn charged_throw_speed(hold: u32) -> f64 {
+    let level = Level::from_ron(WALL).unwrap();
+    let mut world = World::from_level(&level);
+    let mut queue = InputQueue::new();
+
+    assert_eq!(world.throw_mode(), ThrowMode::Charge);
+
+    world.step(&queue.drain());
+
+    queue.push(InputEvent::Cursor(Position::new(500.0, 300.0)));
+    queue.push(InputEvent::Pressed(Action::Throw));
+
+    for _ in 0..hold {
+        world.step(&queue.drain());
+    }
+
+    assert_eq!(world.weapon().state(), &WeaponState::Carried);
+
+    queue.push(InputEvent::Released(Action::Throw));
+    world.step(&queue.drain());
+
+    assert_eq!(world.weapon().state(), &WeaponState::Thrown);
+
+    let velocity = world.weapon().velocity();
+    velocity.x().hypot(velocity.y())
+}
+
+#[test]
+fn charged_throw_power_grows_while_held() {
+    let tap = charged_throw_speed(0);
+    let half = charged_throw_speed(60);
+    let full = charged_throw_speed(240);
+
+    assert!(tap < WEAPON.throw().min_power() * 1.05);
+    assert!(half > tap && full > half);
+    assert!(full > WEAPON.throw().max_power() * 0.95);
+}
+
+#[test]
+fn glass_shatters_and_later_platforms_still_collide() {
+    let level = Level::from_ron(
+        "(
+            name: \"Glass\",
+            player_spawn: (x: 40.0, y: 376.0),
+            platforms: [
+                (x: 300.0, y: 0.0, width: 30.0, height: 576.0, color: \"lightblue\", material: Glass),
+                (x: 450.0, y: 0.0, width: 40.0, height: 576.0, color: \"gray\"),
+            ],
+        )",
+    )
+    .unwrap();
+    let mut world = World::from_level(&level);
+    world.set_throw_mode(ThrowMode::Distance);
+    let mut queue = InputQueue::new();
+
+    world.step(&queue.drain());
+    throw_at(&mut world, &mut queue, Position::new(900.0, 300.0));
+
+    let mut ticks = 0;
+    while !matches!(world.weapon().state(), WeaponState::Stuck(_)) {
+        world.step(&queue.drain());
+        ticks += 1;
+        assert!(ticks < 600, "weapon never stuck");
+    }
+
+    assert_eq!(world.platforms().len(), 1);
+    assert_eq!(world.platforms()[0].position().x(), 450.0);
+    assert_eq!(world.weapon().state(), &WeaponState::Stuck(StuckOn::Right));
+    assert!(world.weapon().position().x() > 300.0);
+    assert!(world.weapon().position().x() < 450.0);
+}
+
+fn air_teleport(momentum: &str) -> Vec<Position> {
+    let level = Level::from_ron(&format!(
+        "(name: \"Air\", width: Some(3000.0), player_spawn: (x: 40.0, y: 376.0), \
+         air_teleport: Some({momentum}))"
+    ))
+    .unwrap();
+    let mut world = World::from_level(&level);
+    world.set_throw_mode(ThrowMode::Distance);
+    let mut queue = InputQueue::new();
+
+    world.step(&queue.drain());
+
+    queue.push(InputEvent::Pressed(Action::Jump));
+    throw_at(&mut world, &mut queue, Position::new(900.0, 100.0));
+    queue.push(InputEvent::Released(Action::Jump));
+
+    for _ in 0..10 {
+        world.step(&queue.drain());
+    }
+
+    assert_eq!(world.weapon().state(), &WeaponState::Thrown);
+
+    queue.push(InputEvent::Pressed(Action::Throw));
+    queue.push(InputEvent::Released(Action::Throw));
+    world.step(&queue.drain());
+
+    assert_eq!(world.weapon().state(), &WeaponState::Carried);
+    assert_eq!(world.teleport(), Some(TeleportResult::Ok));
+    assert_eq!(world.stats().teleports(), 1);
+
+    (0..10)
+        .map(|_| {
+            let position = *world.player().position();
+            world.step(&queue.drain());
+            position
+        })
+        .collect()
+}
+
+#[test]
+fn air_teleport_keeps_player_velocity() {
+    let path = air_teleport("Keep");
+
+    assert!(path[1].y() < path[0].y());
+    assert!(path.iter().all(|position| position.x() == path[0].x()));
+}
+
+#[test]
+fn air_teleport_inherits_weapon_velocity() {
+    let path = air_teleport("Inherit");
+
+    assert!(path[1].y() < path[0].y());
+    assert!(path[9].x() > path[0].x() + 50.0);
+}
+
+#[test]
+fn air_teleport_zeroes_velocity() {
+    let path = air_teleport("Zero");
+
+    assert!(path[1].y() >= path[0].y());
+    assert!(path.iter().all(|position| position.x() == path[0].x()));
+}
+
+#[test]
+fn air_teleport_is_disabled_by_default() {
+    let level = Level::from_ron(WALL).unwrap();
+    let mut world = World::from_level(&level);
+    world.set_throw_mode(ThrowMode::Distance);
+    let mut queue = InputQueue::new();
+
+    world.step(&queue.drain());
+    throw_at(&mut world, &mut queue, Position::new(900.0, 100.0));
+
+    queue.push(InputEvent::Pressed(Action::Throw));
+    world.step(&queue.drain());
+
+    assert_eq!(world.weapon().state(), &WeaponState::Thrown);
+    assert_eq!(world.stats().teleports(), 0);
+}
+
+#[test]
+fn shipped_levels_load() {
+    use crimson_recall::{campaign::Manifest, level::Momentum};
+
+    let manifest =
+        Manifest::from_ron(&std::fs::read_to_string("levels/campaign.ron").unwrap()).unwrap();
+
+    let levels: Vec<Level> = manifest
+        .levels
+        .iter()
+        .map(|name| Level::from_ron(&std::fs::read_to_string(format!("levels/{name}")).unwrap()))
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert!(
+        levels
+            .iter()
+            .any(|level| level.air_teleport == Some(Momentum::Inherit))
+    );
+}
+
+#[test]
+fn tilemap_outside_level_is_rejected() {
+    use crimson_recall::level::{LevelError, ValidationError};
+
+    let error = Level::from_ron(
+        "(
+            name: \"Tiles\",
+            player_spawn: (x: 40.0, y: 376.0),
+            tilemap: Some((tileset: \"assets/tiles.png\", tile_size: 32.0, x: 1100.0, rows: [\"11\"])),
+        )",
+    )
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        LevelError::Invalid(errors) if matches!(errors[..], [ValidationError::TilemapOutOfBounds])
+    ));
+}